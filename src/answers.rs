@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::fs;
+
+/// The directory the answer files are stored in, one file per user.
+const ANSWERS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers");
+
+/// The name of the answer file that holds the answers to the worked examples.
+pub const EXAMPLE: &str = "example";
+
+/// The known answers for one user, keyed by day and part.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Answers {
+    answers: HashMap<(u32, u32), String>,
+}
+
+impl Answers {
+    /// Parses answers from lines like "5 1 SHQWSRBDL", that is, day, part and answer.
+    /// Blank lines and lines starting with '#' are ignored.
+    pub fn parse(text: &str) -> Self {
+        let answers = text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let parts: Vec<&str> = line.splitn(3, char::is_whitespace).collect();
+                if parts.len() != 3 {
+                    panic!("Invalid answer: {}", line);
+                }
+                let day = parts[0].parse().unwrap_or_else(|_| panic!("Invalid day: {}", line));
+                let part = parts[1].parse().unwrap_or_else(|_| panic!("Invalid part: {}", line));
+                ((day, part), String::from(parts[2].trim()))
+            })
+            .collect();
+        Answers { answers }
+    }
+
+    /// Reads the answers for 'user'. A user without an answer file has no known answers.
+    pub fn read(user: &str) -> Self {
+        match fs::read_to_string(format!("{}/{}.txt", ANSWERS_DIR, user)) {
+            Ok(text) => Answers::parse(&text),
+            Err(_) => Answers::default(),
+        }
+    }
+
+    /// Returns the known answer for 'part' of 'day', if any.
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|s| &s[..])
+    }
+}
+
+/// Returns the names of all users that have an answer file, in alphabetical order.
+/// The answers to the worked examples are not included.
+pub fn get_users() -> Vec<String> {
+    let mut users: Vec<String> = fs::read_dir(ANSWERS_DIR)
+        .map(|entries| entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter_map(|name| name.strip_suffix(".txt").map(String::from))
+            .filter(|name| name != EXAMPLE)
+            .collect())
+        .unwrap_or_default();
+    users.sort();
+    users
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::DEFAULT_USER;
+    use super::*;
+
+    #[test]
+    fn test_can_parse_answers() {
+        let answers = Answers::parse("# comment\n1 1 71924\n\n5 2  SHQ WSR \n");
+        assert_eq!(answers.get(1, 1), Some("71924"));
+        assert_eq!(answers.get(5, 2), Some("SHQ WSR"));
+        assert_eq!(answers.get(1, 2), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid part: 1 x 2")]
+    fn test_cannot_parse_invalid_answer() {
+        Answers::parse("1 x 2");
+    }
+
+    #[test]
+    fn test_can_read_answers() {
+        let answers = Answers::read(DEFAULT_USER);
+        assert_eq!(answers.get(5, 1), Some("SHQWSRBDL"));
+        assert_eq!(Answers::read("no-such-user"), Answers::default());
+    }
+
+    #[test]
+    fn test_can_get_users() {
+        let users = get_users();
+        assert!(users.contains(&String::from(DEFAULT_USER)));
+        assert!(!users.contains(&String::from(EXAMPLE)));
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use crate::cycle::fast_forward;
+use crate::geom::Point;
+use crate::grid::{Connectivity, Grid};
+
+/// Advances a dense grid one step, replacing each cell by the result of 'rule' for
+/// the cell and its neighbours. Cells outside the grid have the value 'background'.
+/// If a cell outside the grid would change from 'background', the grid grows by one
+/// cell on each side first, which moves the cell at (x, y) to (x + 1, y + 1).
+pub fn step_dense<T: Clone + PartialEq>(
+    grid: &Vec<Vec<T>>,
+    background: &T,
+    connectivity: Connectivity,
+    rule: impl Fn(&T, &[T]) -> T,
+) -> Vec<Vec<T>> {
+    let height = grid.len() as i32;
+    let width = grid.first().map_or(0, Vec::len) as i32;
+    let get = |pos: &Point| if grid.contains(pos) { grid[pos.y as usize][pos.x as usize].clone() } else { background.clone() };
+    let next = |pos: Point| {
+        let neighbours: Vec<T> = connectivity.neighbours(&pos).iter().map(get).collect();
+        rule(&get(&pos), &neighbours)
+    };
+
+    // Step the grid with a ring of background cells around it
+    let padded: Vec<Vec<T>> = (-1..=height)
+        .map(|y| (-1..=width).map(|x| next(Point::of(x, y))).collect())
+        .collect();
+    let ring_is_background = padded.iter().enumerate().all(|(y, row)| {
+        row.iter().enumerate().all(|(x, cell)| {
+            let inside = y > 0 && y <= height as usize && x > 0 && x <= width as usize;
+            inside || cell == background
+        })
+    });
+    if !ring_is_background {
+        return padded;
+    }
+    padded[1..=height as usize].iter()
+        .map(|row| row[1..=width as usize].to_vec())
+        .collect()
+}
+
+/// Advances a sparse set of live cells one step. A cell is alive after the step if
+/// 'rule' returns true for whether it is alive now, and its number of live neighbours.
+/// Only live cells and their neighbours are looked at, so the set can grow without bounds.
+pub fn step_sparse(
+    live: &HashSet<Point>,
+    connectivity: Connectivity,
+    rule: impl Fn(bool, usize) -> bool,
+) -> HashSet<Point> {
+    let mut counts: HashMap<Point, usize> = live.iter().map(|pos| (*pos, 0)).collect();
+    for pos in live {
+        for neighbour in connectivity.neighbours(pos) {
+            *counts.entry(neighbour).or_insert(0) += 1;
+        }
+    }
+    counts.into_iter()
+        .filter(|(pos, count)| rule(live.contains(pos), *count))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+/// Returns the live cells of a sparse state in a canonical order, to use as a key.
+pub fn sparse_key(live: &HashSet<Point>) -> Vec<Point> {
+    live.cells()
+}
+
+/// Returns 'state' after 'steps' steps.
+pub fn run<S>(state: S, steps: u64, step: impl Fn(&S) -> S) -> S {
+    (0..steps).fold(state, |state, _| step(&state))
+}
+
+/// Steps 'state' until a step no longer changes it, and returns that state and the number
+/// of steps taken, or None if the state still changed after 'max_steps' steps.
+pub fn run_until_fixed_point<S: PartialEq>(state: S, max_steps: u64, step: impl Fn(&S) -> S) -> Option<(S, u64)> {
+    let mut state = state;
+    for steps in 0..=max_steps {
+        let next = step(&state);
+        if next == state {
+            return Some((state, steps));
+        }
+        state = next;
+    }
+    None
+}
+
+/// Returns 'state' after 'steps' steps, like 'run', but stops stepping once the 'key' of
+/// a state repeats, and picks the state in the cycle with 'cycle::fast_forward'. This makes
+/// a billion steps cheap for any automaton that ends in a short cycle. The key must tell
+/// all states apart, like 'sparse_key'.
+pub fn run_with_cycles<S: Clone, K: Hash + Eq>(
+    state: S,
+    steps: u64,
+    key: impl Fn(&S) -> K,
+    step: impl Fn(&S) -> S,
+) -> S {
+    fast_forward(state, steps, key, step, S::clone)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(alive: bool, neighbours: usize) -> bool {
+        neighbours == 3 || (alive && neighbours == 2)
+    }
+
+    fn life_dense(cell: &bool, neighbours: &[bool]) -> bool {
+        life(*cell, neighbours.iter().filter(|n| **n).count())
+    }
+
+    fn to_grid(rows: &[&str]) -> Vec<Vec<bool>> {
+        rows.iter().map(|row| row.chars().map(|c| c == '#').collect()).collect()
+    }
+
+    fn to_set(rows: &[&str]) -> HashSet<Point> {
+        let grid = to_grid(rows);
+        grid.cells().into_iter().filter(|p| grid[p.y as usize][p.x as usize]).collect()
+    }
+
+    #[test]
+    fn test_can_step_dense_blinker() {
+        let blinker = to_grid(&[".....", "..#..", "..#..", "..#..", "....."]);
+        let step = |grid: &Vec<Vec<bool>>| step_dense(grid, &false, Connectivity::Eight, life_dense);
+        let next = step(&blinker);
+        assert_eq!(next, to_grid(&[".....", ".....", ".###.", ".....", "....."]));
+        assert_eq!(step(&next), blinker);
+    }
+
+    #[test]
+    fn test_dense_grid_grows() {
+        let grow = |grid: &Vec<Vec<bool>>| step_dense(grid, &false, Connectivity::Four, |cell, neighbours| {
+            *cell || neighbours.iter().any(|n| *n)
+        });
+        let grid = run(to_grid(&["#"]), 2, grow);
+        assert_eq!(grid, to_grid(&["..#..", ".###.", "#####", ".###.", "..#.."]));
+    }
+
+    #[test]
+    fn test_can_step_sparse_glider() {
+        let glider = to_set(&[".#.", "..#", "###"]);
+        let after = run(glider.clone(), 4, |live| step_sparse(live, Connectivity::Eight, life));
+        let moved: HashSet<Point> = glider.iter().map(|p| p.translate(1, 1)).collect();
+        assert_eq!(after, moved);
+    }
+
+    #[test]
+    fn test_can_run_until_fixed_point() {
+        let erode = |n: usize| move |live: &HashSet<Point>| step_sparse(live, Connectivity::Four, |alive, count| alive && count >= n);
+        let square = to_set(&["###", "###", "###"]);
+        assert_eq!(run_until_fixed_point(square.clone(), 100, erode(2)), Some((square.clone(), 0)));
+        // The square loses its corners, then its arms, then its centre
+        assert_eq!(run_until_fixed_point(square, 100, erode(3)), Some((HashSet::new(), 3)));
+
+        let blink = |live: &HashSet<Point>| step_sparse(live, Connectivity::Eight, life);
+        assert_eq!(run_until_fixed_point(to_set(&["###"]), 100, blink), None);
+    }
+
+    #[test]
+    fn test_can_jump_ahead_with_cycles() {
+        let blinker = to_set(&["###"]);
+        let blink = |live: &HashSet<Point>| step_sparse(live, Connectivity::Eight, life);
+        assert_eq!(run_with_cycles(blinker.clone(), 1_000_000_000, sparse_key, blink), blinker);
+        assert_eq!(run_with_cycles(blinker.clone(), 1_000_000_001, sparse_key, blink), blink(&blinker));
+
+        // A counter that enters a cycle of length 3 after 5 steps
+        let count = |n: &u64| if *n < 7 { n + 1 } else { 5 };
+        for steps in 0..20 {
+            assert_eq!(run_with_cycles(0, steps, |n| *n, count), run(0, steps, count));
+        }
+    }
+}
@@ -0,0 +1,387 @@
+use std::collections::HashMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+use crate::days::{get_solution, DAYS};
+use crate::generate::generate;
+use crate::input::puzzle;
+use crate::lines;
+
+/// The seed used for generated inputs, so they are the same in every benchmark.
+const GENERATOR_SEED: u64 = 2022;
+
+/// The time spent in each phase of solving a puzzle.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_1: Duration,
+    pub part_2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_1 + self.part_2
+    }
+}
+
+/// The result of benchmarking one day with one input.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Benchmark {
+    pub day: u32,
+    /// The name of the input, "puzzle" for the puzzle input, "x10" for the puzzle
+    /// input scaled up ten times, or "gen1000" for a generated input of size 1000.
+    pub input: String,
+    pub bytes: usize,
+    pub timings: Timings,
+}
+
+/// Runs all phases of 'day' with 'input' 'iterations' times, and returns the median
+/// time of each phase. Returns None if the day is not implemented, or if the
+/// solution panicked with 'input'.
+pub fn bench_day(day: u32, input: &str, iterations: usize) -> Option<Timings> {
+    let mut parse = Vec::new();
+    let mut part_1 = Vec::new();
+    let mut part_2 = Vec::new();
+
+    for _ in 0..iterations.max(1) {
+        let mut solution = get_solution(day)?;
+
+        let start = Instant::now();
+        catch_unwind(AssertUnwindSafe(|| solution.parse(input))).ok()?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        catch_unwind(AssertUnwindSafe(|| solution.part_1())).ok()?;
+        part_1.push(start.elapsed());
+
+        let start = Instant::now();
+        catch_unwind(AssertUnwindSafe(|| solution.part_2())).ok()?;
+        part_2.push(start.elapsed());
+    }
+
+    Some(Timings { parse: median(parse), part_1: median(part_1), part_2: median(part_2) })
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+/// Benchmarks each of 'days' with the puzzle input, with the puzzle input scaled up
+/// by each of 'factors', and with a generated input of each of 'sizes'. Days that
+/// are not implemented are skipped.
+pub fn run_benchmarks(days: &[u32], factors: &[usize], sizes: &[usize], iterations: usize) -> Vec<Benchmark> {
+    let mut benchmarks = Vec::new();
+    for day in days.iter().filter(|day| DAYS.contains(day)) {
+        let input = puzzle(*day);
+        let scaled = factors.iter()
+            .filter_map(|factor| scale_input(*day, &input, *factor).map(|s| (format!("x{}", factor), s)));
+        let generated = sizes.iter()
+            .filter_map(|size| generate(*day, GENERATOR_SEED, *size).map(|g| (format!("gen{}", size), g.input)));
+        let inputs: Vec<(String, String)> = [(String::from("puzzle"), input.clone())].into_iter()
+            .chain(scaled)
+            .chain(generated)
+            .collect();
+        for (name, input) in inputs {
+            if let Some(timings) = bench_day(*day, &input, iterations) {
+                benchmarks.push(Benchmark { day: *day, input: name, bytes: input.len(), timings });
+            }
+        }
+    }
+    benchmarks
+}
+
+/// Scales up the puzzle input for 'day' approximately 'factor' times, keeping it
+/// a valid input for that day. Returns None if the day cannot be scaled.
+pub fn scale_input(day: u32, input: &str, factor: usize) -> Option<String> {
+    let factor = factor.max(1);
+    let rows: Vec<String> = lines(input.as_bytes()).collect();
+    match day {
+        // Calories are grouped by blank lines, so the groups must stay separated
+        1 => Some(vec![input.trim(); factor].join("\n\n") + "\n"),
+        2 | 3 | 4 | 9 => Some(vec![rows.join("\n"); factor].join("\n") + "\n"),
+        5 => Some(scale_crane_moves(input, factor)),
+        6 => Some(scale_datastream(input, factor)),
+        7 => Some(scale_transcript(&rows, factor)),
+        8 => Some(scale_forest(&rows, factor)),
+        _ => None,
+    }
+}
+
+/// Repeats the moves, followed by the reversed moves that restore the stacks, so
+/// no stack is ever exhausted.
+fn scale_crane_moves(input: &str, factor: usize) -> String {
+    let (drawing, moves) = input.split_once("\n\n").expect("input has drawing and moves");
+    let moves: Vec<&str> = moves.lines().filter(|line| !line.trim().is_empty()).collect();
+    let reversed: Vec<String> = moves.iter()
+        .rev()
+        .map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            format!("move {} from {} to {}", parts[1], parts[5], parts[3])
+        })
+        .collect();
+
+    let mut scaled = vec![String::from(drawing), String::new()];
+    for _ in 1..factor {
+        scaled.extend(moves.iter().map(|s| String::from(*s)));
+        scaled.extend(reversed.iter().cloned());
+    }
+    scaled.extend(moves.iter().map(|s| String::from(*s)));
+    scaled.join("\n") + "\n"
+}
+
+/// Prepends copies of the first character of the datastream. Any window with a copy
+/// also holds the first character itself, or only copies, so the filler never forms
+/// a marker and the markers only move by the length of the filler.
+fn scale_datastream(input: &str, factor: usize) -> String {
+    let first = input.chars().next().map(String::from).unwrap_or_default();
+    first.repeat(input.len() * (factor - 1)) + input
+}
+
+/// Replays the transcript once in each of 'factor' copies of the root directory. Every
+/// `cd` is rewritten to stay inside the copy: absolute paths start with the copy's
+/// directory, and `..` is dropped where it would climb out of it.
+fn scale_transcript(rows: &[String], factor: usize) -> String {
+    let mut scaled = vec![String::from("$ cd /"), String::from("$ ls")];
+    scaled.extend((0..factor).map(|i| format!("dir copy{}", i)));
+
+    for i in 0..factor {
+        let copy = format!("copy{}", i);
+        // The number of directories below the root, where the copy itself is at depth 1
+        let mut depth = 0;
+        for row in rows {
+            let Some(path) = row.strip_prefix("$ cd ") else {
+                if depth == 0 {
+                    scaled.push(format!("$ cd {}", copy));
+                    depth = 1;
+                }
+                scaled.push(row.clone());
+                continue;
+            };
+            let mut components = Vec::new();
+            if path.starts_with('/') || depth == 0 {
+                components.push(copy.as_str());
+                depth = 1;
+            }
+            for component in path.split('/').filter(|c| !c.is_empty() && *c != ".") {
+                if component != ".." {
+                    components.push(component);
+                    depth += 1;
+                } else if depth > 1 {
+                    components.push(component);
+                    depth -= 1;
+                }
+            }
+            if !components.is_empty() {
+                let root = if path.starts_with('/') { "/" } else { "" };
+                scaled.push(format!("$ cd {}{}", root, components.join("/")));
+            }
+        }
+        scaled.push(String::from("$ cd /"));
+    }
+    scaled.join("\n") + "\n"
+}
+
+/// Tiles the forest in a square, so the area grows approximately 'factor' times.
+fn scale_forest(rows: &[String], factor: usize) -> String {
+    let tiles = ((factor as f64).sqrt().round() as usize).max(1);
+    let mut scaled = Vec::new();
+    for _ in 0..tiles {
+        scaled.extend(rows.iter().map(|row| row.repeat(tiles)));
+    }
+    scaled.join("\n") + "\n"
+}
+
+/// Formats the benchmarks as a CSV report that can be saved and compared later.
+pub fn format_report(benchmarks: &[Benchmark]) -> String {
+    let mut report = String::from("day,input,bytes,parse_ns,part_1_ns,part_2_ns\n");
+    for b in benchmarks {
+        report += &format!(
+            "{},{},{},{},{},{}\n",
+            b.day, b.input, b.bytes,
+            b.timings.parse.as_nanos(), b.timings.part_1.as_nanos(), b.timings.part_2.as_nanos(),
+        );
+    }
+    report
+}
+
+/// Parses a CSV report written by 'format_report'.
+pub fn parse_report(text: &str) -> Vec<Benchmark> {
+    text.lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() != 6 {
+                panic!("Invalid benchmark: {}", line);
+            }
+            let number = |s: &str| s.parse::<u64>().unwrap_or_else(|_| panic!("Not an integer: {}", s));
+            Benchmark {
+                day: number(fields[0]) as u32,
+                input: String::from(fields[1]),
+                bytes: number(fields[2]) as usize,
+                timings: Timings {
+                    parse: Duration::from_nanos(number(fields[3])),
+                    part_1: Duration::from_nanos(number(fields[4])),
+                    part_2: Duration::from_nanos(number(fields[5])),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Formats the benchmarks as a table. If a baseline is given, each phase also shows
+/// the change in percent compared to the benchmark of the same day and input.
+pub fn format_table(benchmarks: &[Benchmark], baseline: &[Benchmark]) -> String {
+    let baseline: HashMap<(u32, &str), &Timings> = baseline.iter()
+        .map(|b| ((b.day, &b.input[..]), &b.timings))
+        .collect();
+    let input_width = benchmarks.iter().map(|b| b.input.len()).chain(["Input".len()]).max().unwrap_or(0);
+
+    let mut table = format!(
+        "Day  {:<iw$}  {:>10}  {:>20}  {:>20}  {:>20}  {:>20}\n",
+        "Input", "Bytes", "Parse", "Part 1", "Part 2", "Total",
+        iw = input_width,
+    );
+    for b in benchmarks {
+        let old = baseline.get(&(b.day, &b.input[..]));
+        let cell = |phase: fn(&Timings) -> Duration| {
+            let time = format!("{:.3} ms", phase(&b.timings).as_secs_f64() * 1000.0);
+            match old.map(|t| phase(t)) {
+                Some(old) if !old.is_zero() => {
+                    let change = 100.0 * (phase(&b.timings).as_secs_f64() / old.as_secs_f64() - 1.0);
+                    format!("{} {:>+6.1}%", time, change)
+                }
+                _ => time,
+            }
+        };
+        table += &format!(
+            "{:>3}  {:<iw$}  {:>10}  {:>20}  {:>20}  {:>20}  {:>20}\n",
+            b.day, b.input, b.bytes,
+            cell(|t| t.parse), cell(|t| t.part_1), cell(|t| t.part_2), cell(|t| t.total()),
+            iw = input_width,
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::example;
+    use super::*;
+
+    fn solve(day: u32, input: &str) -> (String, String) {
+        let mut solution = get_solution(day).unwrap();
+        solution.parse(input);
+        (solution.part_1(), solution.part_2())
+    }
+
+    #[test]
+    fn test_can_bench_day() {
+        assert!(bench_day(6, &example(6), 3).is_some());
+        assert!(bench_day(25, "", 3).is_none());
+        // No marker in the input, so the solution panics
+        assert!(bench_day(6, "abc", 3).is_none());
+    }
+
+    #[test]
+    fn test_can_run_benchmarks() {
+        let benchmarks = run_benchmarks(&[4], &[2], &[100], 1);
+        assert_eq!(benchmarks.len(), 3);
+        assert_eq!(benchmarks[0].input, "puzzle");
+        assert_eq!(benchmarks[1].input, "x2");
+        assert_eq!(benchmarks[1].bytes, 2 * benchmarks[0].bytes);
+        assert_eq!(benchmarks[2].input, "gen100");
+
+        assert!(run_benchmarks(&[25], &[2], &[100], 1).is_empty());
+    }
+
+    #[test]
+    fn test_can_scale_examples() {
+        for day in 1..=9 {
+            let input = example(day);
+            let scaled = scale_input(day, &input, 4).unwrap();
+            assert!(scaled.len() > input.len(), "day {}", day);
+            // Scaled inputs must be valid inputs
+            solve(day, &scaled);
+        }
+        assert_eq!(scale_input(25, "", 4), None);
+    }
+
+    #[test]
+    fn test_scaled_inputs_have_predictable_answers() {
+        assert_eq!(solve(1, &scale_input(1, &example(1), 3).unwrap()), (String::from("24000"), String::from("72000")));
+        assert_eq!(solve(4, &scale_input(4, &example(4), 3).unwrap()), (String::from("6"), String::from("12")));
+        assert_eq!(solve(5, &scale_input(5, &example(5), 3).unwrap()), solve(5, &example(5)));
+        assert_eq!(solve(6, &scale_input(6, &example(6), 1).unwrap()), solve(6, &example(6)));
+        // The datastream is 16 characters long, so the markers move by 32
+        assert_eq!(solve(6, "gzabcdefghijklmn"), (String::from("4"), String::from("15")));
+        assert_eq!(solve(6, &scale_input(6, "gzabcdefghijklmn", 3).unwrap()), (String::from("36"), String::from("47")));
+        assert_eq!(solve(7, &scale_input(7, &example(7), 2).unwrap()).0, "190874");
+    }
+
+    #[test]
+    fn test_can_scale_transcript() {
+        let rows: Vec<String> = ["$ cd /", "$ ls", "1 a", "dir b", "$ cd b", "$ ls", "2 c"].iter()
+            .map(|s| String::from(*s))
+            .collect();
+        let scaled: Vec<String> = lines(scale_transcript(&rows, 2).as_bytes()).collect();
+        assert_eq!(scaled, vec![
+            "$ cd /", "$ ls", "dir copy0", "dir copy1",
+            "$ cd /copy0", "$ ls", "1 a", "dir b", "$ cd b", "$ ls", "2 c", "$ cd /",
+            "$ cd /copy1", "$ ls", "1 a", "dir b", "$ cd b", "$ ls", "2 c", "$ cd /",
+        ]);
+    }
+
+    #[test]
+    fn test_can_scale_transcript_with_paths() {
+        let rows: Vec<String> = ["$ ls", "$ cd a/b", "$ ls", "3 c", "$ cd /d", "$ cd ../../..", "$ ls", "4 e"].iter()
+            .map(|s| String::from(*s))
+            .collect();
+        let scaled: Vec<String> = lines(scale_transcript(&rows, 2).as_bytes()).collect();
+        assert_eq!(scaled[4..14], [
+            "$ cd copy0", "$ ls", "$ cd a/b", "$ ls", "3 c", "$ cd /copy0/d", "$ cd ..", "$ ls", "4 e", "$ cd /",
+        ]);
+
+        // The original has a, a/b, d and the root at sizes 3, 3, 0 and 7, so each copy adds
+        // 13 to part 1 and the root adds 14, as long as no files escape the copies
+        assert_eq!(solve(7, &(rows.join("\n") + "\n")).0, "13");
+        assert_eq!(solve(7, &scale_transcript(&rows, 2)).0, "40");
+    }
+
+    #[test]
+    fn test_can_write_and_read_report() {
+        let benchmarks = vec![Benchmark {
+            day: 7,
+            input: String::from("x10"),
+            bytes: 1234,
+            timings: Timings {
+                parse: Duration::from_nanos(10),
+                part_1: Duration::from_nanos(200),
+                part_2: Duration::from_nanos(3_000),
+            },
+        }];
+        let report = format_report(&benchmarks);
+        assert_eq!(report, "day,input,bytes,parse_ns,part_1_ns,part_2_ns\n7,x10,1234,10,200,3000\n");
+        assert_eq!(parse_report(&report), benchmarks);
+    }
+
+    #[test]
+    fn test_can_format_table_with_baseline() {
+        let benchmark = |part_1: u64| Benchmark {
+            day: 1,
+            input: String::from("puzzle"),
+            bytes: 10,
+            timings: Timings {
+                parse: Duration::from_millis(1),
+                part_1: Duration::from_millis(part_1),
+                part_2: Duration::from_millis(1),
+            },
+        };
+        let table = format_table(&[benchmark(3)], &[benchmark(2)]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains("1.000 ms   +0.0%"));
+        assert!(lines[1].contains("3.000 ms  +50.0%"));
+        assert!(lines[1].contains("5.000 ms  +25.0%"));
+    }
+}
@@ -0,0 +1,466 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+/// An integer of any size, for answers that overflow i64.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    /// The digits in base 2^32, least significant first, without leading zeros,
+    /// so that zero has no digits and is never negative.
+    magnitude: Vec<u32>,
+}
+
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+impl BigInt {
+    pub fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn of(negative: bool, magnitude: Vec<u32>) -> Self {
+        let mut magnitude = magnitude;
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        let negative = negative && !magnitude.is_empty();
+        BigInt { negative, magnitude }
+    }
+
+    /// Parses a decimal integer with an optional sign, or returns None if 's' is not one.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let mut magnitude: Vec<u32> = Vec::new();
+        let first = digits.len() % DECIMAL_CHUNK_DIGITS;
+        let chunks = std::iter::once(&digits[..first])
+            .chain(digits.as_bytes()[first..].chunks(DECIMAL_CHUNK_DIGITS).map(|c| std::str::from_utf8(c).unwrap()));
+        for chunk in chunks.filter(|c| !c.is_empty()) {
+            let factor = 10u32.pow(chunk.len() as u32);
+            mul_add_small(&mut magnitude, factor, chunk.parse().unwrap());
+        }
+        Some(BigInt::of(negative, magnitude))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> Self {
+        BigInt::of(false, self.magnitude.clone())
+    }
+
+    /// Returns the quotient and remainder of dividing by 'other', rounding the quotient
+    /// towards zero, so that the remainder has the sign of this number, like i64 does.
+    /// Panics if 'other' is zero.
+    pub fn div_rem(&self, other: &BigInt) -> (BigInt, BigInt) {
+        assert!(!other.is_zero(), "Division by zero");
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &other.magnitude);
+        (
+            BigInt::of(self.negative != other.negative, quotient),
+            BigInt::of(self.negative, remainder),
+        )
+    }
+
+    /// Returns this number as i128, or None if it does not fit.
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.magnitude.len() > 4 {
+            return None;
+        }
+        let magnitude = self.magnitude.iter().rev().fold(0u128, |m, d| (m << 32) | *d as u128);
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    /// Returns this number as i64, or None if it does not fit.
+    pub fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|n| i64::try_from(n).ok())
+    }
+}
+
+/// The error of parsing a string that is not a decimal integer as a BigInt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError {
+    input: String,
+}
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Not an integer: {}", self.input)
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    /// Parses a decimal integer with an optional sign, like 'BigInt::parse'.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BigInt::parse(s).ok_or_else(|| ParseBigIntError { input: String::from(s) })
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(n: i128) -> Self {
+        let magnitude = n.unsigned_abs();
+        BigInt::of(n < 0, (0..4).map(|i| (magnitude >> (32 * i)) as u32).collect())
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(n: i64) -> Self {
+        BigInt::from(n as i128)
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(n: i32) -> Self {
+        BigInt::from(n as i128)
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(n: u64) -> Self {
+        BigInt::from(n as i128)
+    }
+}
+
+/// Multiplies 'magnitude' by 'factor' and adds 'addend', in place.
+fn mul_add_small(magnitude: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+    for digit in magnitude.iter_mut() {
+        let value = *digit as u64 * factor as u64 + carry;
+        *digit = value as u32;
+        carry = value >> 32;
+    }
+    if carry > 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+/// Divides 'magnitude' by 'divisor' in place, and returns the remainder.
+fn div_rem_small(magnitude: &mut [u32], divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+    for digit in magnitude.iter_mut().rev() {
+        let value = (remainder << 32) | *digit as u64;
+        *digit = (value / divisor as u64) as u32;
+        remainder = value % divisor as u64;
+    }
+    remainder as u32
+}
+
+fn compare_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum: Vec<u32> = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, digit) in long.iter().enumerate() {
+        let value = *digit as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        sum.push(value as u32);
+        carry = value >> 32;
+    }
+    sum.push(carry as u32);
+    sum
+}
+
+/// Returns 'a' - 'b', for 'a' at least 'b'.
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference: Vec<u32> = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, digit) in a.iter().enumerate() {
+        let mut value = *digit as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if value < 0 {
+            value += 1 << 32;
+            borrow = 1;
+        }
+        difference.push(value as u32);
+    }
+    difference
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, y) in b.iter().enumerate() {
+            let value = product[i + j] as u64 + *x as u64 * *y as u64 + carry;
+            product[i + j] = value as u32;
+            carry = value >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    product
+}
+
+/// Divides 'a' by 'b' by long division, one bit at a time, or one digit at a time if
+/// 'b' has a single digit.
+fn div_rem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if b.len() == 1 {
+        let mut quotient = a.to_vec();
+        let remainder = div_rem_small(&mut quotient, b[0]);
+        return (quotient, vec![remainder]);
+    }
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder: Vec<u32> = Vec::new();
+    for bit in (0..a.len() * 32).rev() {
+        mul_add_small(&mut remainder, 2, (a[bit / 32] >> (bit % 32)) & 1);
+        if compare_magnitude(&remainder, b) != Ordering::Less {
+            remainder = sub_magnitude(&remainder, b);
+            while remainder.last() == Some(&0) {
+                remainder.pop();
+            }
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (quotient, remainder)
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut magnitude = self.magnitude.clone();
+        let mut chunks: Vec<u32> = Vec::new();
+        while !magnitude.is_empty() {
+            chunks.push(div_rem_small(&mut magnitude, DECIMAL_CHUNK));
+            while magnitude.last() == Some(&0) {
+                magnitude.pop();
+            }
+        }
+        let mut digits = chunks.last().map_or(String::from("0"), |c| c.to_string());
+        for chunk in chunks.iter().rev().skip(1) {
+            digits.push_str(&format!("{:09}", chunk));
+        }
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::of(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::of(self.negative, add_magnitude(&self.magnitude, &other.magnitude));
+        }
+        match compare_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::of(other.negative, sub_magnitude(&other.magnitude, &self.magnitude)),
+            _ => BigInt::of(self.negative, sub_magnitude(&self.magnitude, &other.magnitude)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::of(self.negative != other.negative, mul_magnitude(&self.magnitude, &other.magnitude))
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).1
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -&self
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: BigInt) -> BigInt {
+        &self + &other
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: BigInt) -> BigInt {
+        &self - &other
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: BigInt) -> BigInt {
+        &self * &other
+    }
+}
+
+impl Div for BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: BigInt) -> BigInt {
+        &self / &other
+    }
+}
+
+impl Rem for BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: BigInt) -> BigInt {
+        &self % &other
+    }
+}
+
+impl Sum for BigInt {
+    fn sum<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::zero(), |sum, n| sum + n)
+    }
+}
+
+impl Product for BigInt {
+    fn product<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::from(1), |product, n| product * n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{check, no_shrink};
+    use crate::random::Random;
+
+    #[test]
+    fn test_can_parse_and_display() {
+        for s in ["0", "7", "-7", "4294967296", "-18446744073709551616", "1000000000000000000000000000001"] {
+            assert_eq!(s.parse::<BigInt>().unwrap().to_string(), s);
+        }
+        assert_eq!(" +0042 ".parse::<BigInt>().unwrap().to_string(), "42");
+        assert_eq!("-0".parse::<BigInt>().unwrap(), BigInt::zero());
+        assert_eq!(BigInt::parse("12a"), None);
+        assert_eq!(BigInt::parse("-"), None);
+        assert_eq!(BigInt::parse(""), None);
+        assert_eq!(format!("{:>6}", BigInt::from(-42)), "   -42");
+    }
+
+    #[test]
+    fn test_from_str_fails_on_invalid_input() {
+        let error = "1.5".parse::<BigInt>().unwrap_err();
+        assert_eq!(error.to_string(), "Not an integer: 1.5");
+        assert!("".parse::<BigInt>().is_err());
+    }
+
+    #[test]
+    fn test_can_compute_beyond_i128() {
+        let factorial: BigInt = (1..=30).map(BigInt::from).product();
+        assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+        let power: BigInt = (0..128).map(|_| BigInt::from(2)).product();
+        assert_eq!(power.to_string(), "340282366920938463463374607431768211456");
+        assert_eq!(power.to_i128(), None);
+        assert_eq!((&power - &BigInt::from(1)).to_i128(), None);
+        assert_eq!((-&power / BigInt::from(2)).to_i128(), Some(i128::MIN));
+        assert_eq!((&power / &BigInt::from(2) - BigInt::from(1)).to_i128(), Some(i128::MAX));
+        assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+        assert_eq!((&factorial / &(1..=28).map(BigInt::from).product()).to_i64(), Some(29 * 30));
+        assert_eq!(&factorial % &"1000000000007".parse::<BigInt>().unwrap(), BigInt::from(265252859812191058636308480000000i128 % 1000000000007));
+    }
+
+    #[test]
+    fn test_can_compare() {
+        let mut numbers: Vec<BigInt> = ["5", "-18446744073709551616", "0", "-3", "18446744073709551616"]
+            .iter()
+            .map(|s| s.parse::<BigInt>().unwrap())
+            .collect();
+        numbers.sort();
+        let sorted: Vec<String> = numbers.iter().map(BigInt::to_string).collect();
+        assert_eq!(sorted, vec!["-18446744073709551616", "-3", "0", "5", "18446744073709551616"]);
+    }
+
+    fn random_i64(random: &mut Random) -> i64 {
+        (random.next_u64() as i64) >> random.index(64)
+    }
+
+    fn random_operands(random: &mut Random, _size: usize) -> (i64, i64, i64) {
+        (random_i64(random), random_i64(random), random_i64(random))
+    }
+
+    #[test]
+    fn property_arithmetic_agrees_with_i128() {
+        check(random_operands, no_shrink, |(a, b, c)| {
+            let (a, b, c) = (*a as i128, *b as i128, *c as i128);
+            let big = |n: i128| BigInt::from(n);
+            let dividend = a * b + c;
+            let divisor = if c == 0 { 1 } else { c };
+            big(a) * big(b) + big(c) == big(dividend)
+                && big(a) - big(b) == big(a - b)
+                && big(dividend) / big(divisor) == big(dividend / divisor)
+                && big(dividend) % big(divisor) == big(dividend % divisor)
+                && big(dividend) / big(a | 1 << 40) == big(dividend / (a | 1 << 40))
+                && big(dividend).to_string() == dividend.to_string()
+                && dividend.to_string().parse::<BigInt>().ok().and_then(|n| n.to_i128()) == Some(dividend)
+                && big(a).cmp(&big(b)) == a.cmp(&b)
+        });
+    }
+}
@@ -0,0 +1,350 @@
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::Path;
+use std::process::exit;
+use aoc_2022::answers::{get_users, EXAMPLE};
+use aoc_2022::bench::{format_report, parse_report, run_benchmarks};
+use aoc_2022::{lines, to_vector_of_str};
+use aoc_2022::days::DAYS;
+use aoc_2022::days::day07::{FileSystem, Traversal};
+use aoc_2022::geom::Point;
+use aoc_2022::days::day08::{compute_viewshed, get_map, rank_scenic_spots, spots_to_csv, to_csv, to_matrix_of_u8, to_pgm};
+use aoc_2022::input::{read_file, Source, DEFAULT_USER};
+use aoc_2022::runner::{format_table, solve, verify_user, Status};
+use aoc_2022::scaffold::{create_day, CRATE_ROOT};
+
+const USAGE: &str = "\
+Usage: aoc --day N [--file PATH | --text INPUT | --example | --puzzle]
+       aoc verify [--user NAME]... [--all-users] [--examples]
+       aoc bench [--day N]... [--scale FACTOR]... [--generate SIZE]... [--iterations N] [--save FILE] [--baseline FILE]
+       aoc generate --day N [--seed SEED] [--size SIZE]
+       aoc new-day --day N
+       aoc forest [--file PATH | --example] [--top N | --map NAME | --viewshed X,Y [--eye H]] [--format csv | pgm]
+       aoc filesystem [--file PATH | --example | --dir DIR] [--format json | du | du-bytes | transcript | --skeleton DIR] [--order ORDER]
+
+Without a command, solves day N with the input from the first of: --file, --text,
+--example, standard input if it is not a terminal, or the puzzle input.
+
+Solve options:
+  --day N             Solves day N
+  --file PATH         Reads the input from the file at PATH
+  --text INPUT        Uses INPUT as the input, for example pasted from the puzzle text
+  --example           Uses the worked example from the puzzle text
+  --puzzle            Uses the puzzle input, even if standard input is not a terminal
+
+Commands:
+  verify              Runs all implemented days and compares the answers to the known answers
+  bench               Measures the time spent parsing, and solving each part, of each day
+  generate            Prints a random input to stdout, and its answers to stderr
+  new-day             Creates and registers a new day, with placeholders for its inputs
+  forest              Ranks the most scenic trees of day 8, or exports a map of the forest
+  filesystem          Exports the file system of a day 7 transcript, or of a real directory
+
+Verify options:
+  --user NAME         Verifies the inputs and answers of user NAME, may be repeated
+  --all-users         Verifies the inputs and answers of all users with an answer file
+  --examples          Verifies the worked examples from the puzzle texts
+
+Bench options:
+  --day N             Benchmarks day N only, may be repeated
+  --scale FACTOR      Also benchmarks the puzzle input scaled up FACTOR times, may be repeated
+  --generate SIZE     Also benchmarks a generated input with SIZE items, may be repeated
+  --iterations N      Reports the median of N runs, 10 by default
+  --save FILE         Saves the results as a CSV report
+  --baseline FILE     Compares the results to a CSV report saved earlier
+
+Generate options:
+  --day N             Generates an input for day N
+  --seed SEED         Seeds the random number generator, 1 by default
+  --size SIZE         Generates SIZE items, like elves, moves or files, 100 by default
+
+New day options:
+  --day N             Creates day N, unless it already exists
+
+Forest options:
+  --file PATH         Reads the forest from the file at PATH, or else from standard input
+                      if it is not a terminal, or else from the puzzle input
+  --example           Uses the worked example from the puzzle text
+  --top N             Prints the N most scenic trees as CSV, 10 by default
+  --map NAME          Prints the map NAME: heights, scenic, visible, visible-EDGE or
+                      horizon-EDGE, where EDGE is top, bottom, left or right
+  --viewshed X,Y      Prints the cells visible from the top of the tree at X,Y, and
+                      the number of visible and hidden cells to standard error
+  --eye H             Raises the eye of the observer H above the top of the tree, 0 by default
+  --format FORMAT     Prints the map as csv, the default, or as a pgm image
+
+Filesystem options:
+  --file PATH         Reads the transcript from the file at PATH, or else from standard
+                      input if it is not a terminal, or else from the puzzle input
+  --example           Uses the worked example from the puzzle text
+  --dir DIR           Reads the file system from the directory DIR on disk instead
+  --format FORMAT     Prints the file system as json, the default, like du -a in blocks
+                      of 1024 bytes, like du -ab in bytes with du-bytes, or as a
+                      transcript that lists every directory once
+  --order ORDER       Visits the directories of a transcript depth-first, the default,
+                      or breadth-first
+  --skeleton DIR      Creates the directories in DIR, with sparse files of the same sizes";
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    exit(2)
+}
+
+/// Returns standard input as the source of the input, unless it is a terminal.
+fn default_source() -> Source {
+    if io::stdin().is_terminal() { Source::Puzzle } else { Source::Stdin }
+}
+
+fn solve_day(args: &[String]) {
+    let mut day: Option<u32> = None;
+    let mut source: Option<Source> = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(iter.next())),
+            "--file" => source = Some(Source::File(iter.next().cloned().unwrap_or_else(|| usage_error("Missing file name")))),
+            "--text" => source = Some(Source::Text(iter.next().cloned().unwrap_or_else(|| usage_error("Missing input")))),
+            "--example" => source = Some(Source::Example),
+            "--puzzle" => source = Some(Source::Puzzle),
+            _ => usage_error(&format!("Unknown option: {}", arg)),
+        }
+    }
+    let day = day.unwrap_or_else(|| usage_error("Missing day"));
+    // The inputs of a day are only registered together with its solution
+    if !DAYS.contains(&day) {
+        usage_error(&format!("No solution for day {}", day));
+    }
+    let source = source.unwrap_or_else(default_source);
+
+    match solve(day, &source.read(day)) {
+        Some((part_1, part_2)) => println!("Part 1: {}\nPart 2: {}", part_1, part_2),
+        None => usage_error(&format!("No solution for day {}", day)),
+    }
+}
+
+fn verify(args: &[String]) {
+    let mut users: Vec<String> = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--user" => users.push(iter.next().cloned().unwrap_or_else(|| usage_error("Missing user name"))),
+            "--all-users" => users.extend(get_users()),
+            "--examples" => users.push(String::from(EXAMPLE)),
+            _ => usage_error(&format!("Unknown option: {}", arg)),
+        }
+    }
+    if users.is_empty() {
+        users.push(String::from(DEFAULT_USER));
+    }
+
+    let results: Vec<_> = users.iter().flat_map(|user| verify_user(user)).collect();
+    print!("{}", format_table(&results));
+    if results.iter().any(|v| v.status == Status::Fail || v.status == Status::Error) {
+        exit(1);
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(arg: Option<&String>) -> T {
+    arg.and_then(|s| s.parse().ok()).unwrap_or_else(|| usage_error("Missing or invalid number"))
+}
+
+fn bench(args: &[String]) {
+    let mut days: Vec<u32> = Vec::new();
+    let mut factors: Vec<usize> = Vec::new();
+    let mut sizes: Vec<usize> = Vec::new();
+    let mut iterations = 10;
+    let mut save: Option<String> = None;
+    let mut baseline: Option<String> = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--day" => days.push(parse_number(iter.next())),
+            "--scale" => factors.push(parse_number(iter.next())),
+            "--generate" => sizes.push(parse_number(iter.next())),
+            "--iterations" => iterations = parse_number(iter.next()),
+            "--save" => save = Some(iter.next().cloned().unwrap_or_else(|| usage_error("Missing file name"))),
+            "--baseline" => baseline = Some(iter.next().cloned().unwrap_or_else(|| usage_error("Missing file name"))),
+            _ => usage_error(&format!("Unknown option: {}", arg)),
+        }
+    }
+    if let Some(day) = days.iter().find(|day| !DAYS.contains(day)) {
+        usage_error(&format!("No solution for day {}", day));
+    }
+    if days.is_empty() {
+        days.extend_from_slice(DAYS);
+    }
+
+    let benchmarks = run_benchmarks(&days, &factors, &sizes, iterations);
+    let baseline = baseline.map(|path| parse_report(&read_file(&path))).unwrap_or_default();
+    print!("{}", aoc_2022::bench::format_table(&benchmarks, &baseline));
+    if let Some(path) = save {
+        fs::write(&path, format_report(&benchmarks)).unwrap_or_else(|_| panic!("Failed to write file: {}", path));
+    }
+}
+
+fn generate(args: &[String]) {
+    let mut day: Option<u32> = None;
+    let mut seed = 1;
+    let mut size = 100;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(iter.next())),
+            "--seed" => seed = parse_number(iter.next()),
+            "--size" => size = parse_number(iter.next()),
+            _ => usage_error(&format!("Unknown option: {}", arg)),
+        }
+    }
+    let day = day.unwrap_or_else(|| usage_error("Missing day"));
+
+    match aoc_2022::generate::generate(day, seed, size) {
+        Some(generated) => {
+            print!("{}", generated.input);
+            eprintln!("Part 1: {}\nPart 2: {}", generated.part_1, generated.part_2);
+        }
+        None => usage_error(&format!("No generator for day {}", day)),
+    }
+}
+
+fn new_day(args: &[String]) {
+    let mut day: Option<u32> = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(iter.next())),
+            _ => usage_error(&format!("Unknown option: {}", arg)),
+        }
+    }
+    let day = day.unwrap_or_else(|| usage_error("Missing day"));
+
+    match create_day(Path::new(CRATE_ROOT), day) {
+        Ok(files) => files.iter().for_each(|file| println!("{}", file.display())),
+        Err(message) => {
+            eprintln!("{}", message);
+            exit(1);
+        }
+    }
+}
+
+fn forest(args: &[String]) {
+    let mut source: Option<Source> = None;
+    let mut top = 10;
+    let mut map: Option<String> = None;
+    let mut format = String::from("csv");
+    let mut observer: Option<Point> = None;
+    let mut eye = 0;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--file" => source = Some(Source::File(iter.next().cloned().unwrap_or_else(|| usage_error("Missing file name")))),
+            "--example" => source = Some(Source::Example),
+            "--top" => top = parse_number(iter.next()),
+            "--map" => map = Some(iter.next().cloned().unwrap_or_else(|| usage_error("Missing map name"))),
+            "--format" => format = iter.next().cloned().unwrap_or_else(|| usage_error("Missing format")),
+            "--viewshed" => {
+                let coordinates = iter.next().and_then(|s| s.split_once(','))
+                    .unwrap_or_else(|| usage_error("Missing or invalid observer"));
+                let x = parse_number(Some(&String::from(coordinates.0)));
+                let y = parse_number(Some(&String::from(coordinates.1)));
+                observer = Some(Point::of(x, y));
+            }
+            "--eye" => eye = parse_number(iter.next()),
+            _ => usage_error(&format!("Unknown option: {}", arg)),
+        }
+    }
+    let input = source.unwrap_or_else(default_source).read(8);
+    let rows: Vec<String> = lines(input.as_bytes()).collect();
+    let forest = to_matrix_of_u8(&to_vector_of_str(&rows));
+
+    let matrix = match (map, observer) {
+        (None, None) => {
+            print!("{}", spots_to_csv(&rank_scenic_spots(&forest, top)));
+            return;
+        }
+        (Some(name), _) => get_map(&forest, &name).unwrap_or_else(|| usage_error(&format!("Unknown map: {}", name))),
+        (None, Some(observer)) => {
+            if observer.y < 0 || observer.y as usize >= forest.len() || observer.x < 0 || observer.x as usize >= forest[0].len() {
+                usage_error("Observer is outside the forest");
+            }
+            let viewshed = compute_viewshed(&forest, observer, eye);
+            eprintln!("Visible: {}\nHidden: {}", viewshed.visible, viewshed.hidden);
+            viewshed.mask.into_iter().map(|row| row.into_iter().map(i64::from).collect()).collect()
+        }
+    };
+    match format.as_str() {
+        "csv" => print!("{}", to_csv(&matrix)),
+        "pgm" => print!("{}", to_pgm(&matrix)),
+        _ => usage_error(&format!("Unknown format: {}", format)),
+    }
+}
+
+fn filesystem(args: &[String]) {
+    let mut source: Option<Source> = None;
+    let mut format = String::from("json");
+    let mut skeleton: Option<String> = None;
+    let mut dir: Option<String> = None;
+    let mut traversal = Traversal::DepthFirst;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--file" => source = Some(Source::File(iter.next().cloned().unwrap_or_else(|| usage_error("Missing file name")))),
+            "--example" => source = Some(Source::Example),
+            "--format" => format = iter.next().cloned().unwrap_or_else(|| usage_error("Missing format")),
+            "--skeleton" => skeleton = Some(iter.next().cloned().unwrap_or_else(|| usage_error("Missing directory"))),
+            "--dir" => dir = Some(iter.next().cloned().unwrap_or_else(|| usage_error("Missing directory"))),
+            "--order" => traversal = match iter.next().map(String::as_str) {
+                Some("depth") => Traversal::DepthFirst,
+                Some("breadth") => Traversal::BreadthFirst,
+                _ => usage_error("Missing or invalid order"),
+            },
+            _ => usage_error(&format!("Unknown option: {}", arg)),
+        }
+    }
+    let file_system = match dir {
+        Some(dir) => FileSystem::from_dir(Path::new(&dir)).unwrap_or_else(|e| {
+            eprintln!("Failed to read {}: {}", dir, e);
+            exit(1);
+        }),
+        None => {
+            let input = source.unwrap_or_else(default_source).read(7);
+            let transcript: Vec<String> = lines(input.as_bytes()).collect();
+            FileSystem::from_transcript(&to_vector_of_str(&transcript))
+        }
+    };
+    for path in file_system.get_unknown_sizes() {
+        eprintln!("Unknown size: {}", path);
+    }
+
+    if let Some(dir) = skeleton {
+        if let Err(e) = file_system.write_skeleton(Path::new(&dir)) {
+            eprintln!("Failed to write skeleton: {}", e);
+            exit(1);
+        }
+        return;
+    }
+    match format.as_str() {
+        "json" => print!("{}", file_system.to_json()),
+        "du" => print!("{}", file_system.to_du(1024)),
+        "du-bytes" => print!("{}", file_system.to_du(1)),
+        "transcript" => println!("{}", file_system.to_transcript(traversal).join("\n")),
+        _ => usage_error(&format!("Unknown format: {}", format)),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("generate") => generate(&args[1..]),
+        Some("new-day") => new_day(&args[1..]),
+        Some("forest") => forest(&args[1..]),
+        Some("filesystem") => filesystem(&args[1..]),
+        Some("--help") | Some("-h") => println!("{}", USAGE),
+        // Without a command, the options of solving a day may come in any order
+        Some(option) if option.starts_with("--") => solve_day(&args),
+        Some(command) => usage_error(&format!("Unknown command: {}", command)),
+        None => usage_error("Missing command"),
+    }
+}
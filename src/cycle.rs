@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The cycle that a sequence of states ends in: the state after 'start' steps is the first
+/// state that repeats, and it repeats every 'length' steps.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Cycle {
+    pub start: u64,
+    pub length: u64,
+}
+
+impl Cycle {
+    /// Returns the earliest step with the same state as step 'n'.
+    pub fn equivalent_step(&self, n: u64) -> u64 {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// Returns the value of a metric at step 'n', given its 'values' for the steps up to
+    /// at least 'start' + 'length'. The metric must grow by the same amount in every
+    /// cycle, like the height of a tower, or not at all, like a function of the state.
+    pub fn extrapolate(&self, n: u64, values: &[i64]) -> i64 {
+        if (n as usize) < values.len() {
+            return values[n as usize];
+        }
+        let growth = values[(self.start + self.length) as usize] - values[self.start as usize];
+        let cycles = (n - self.start) / self.length;
+        values[self.equivalent_step(n) as usize] + cycles as i64 * growth
+    }
+}
+
+/// Finds the cycle of the states that 'step' produces from 'initial', using Floyd's
+/// tortoise and hare, which keeps only two states. Loops forever if there is no cycle.
+pub fn floyd<S: Clone + PartialEq>(initial: &S, step: impl Fn(&S) -> S) -> Cycle {
+    let mut tortoise = step(initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&step(&hare));
+    }
+
+    // The distance from the start to the cycle equals the distance from the meeting point
+    let mut start = 0;
+    tortoise = initial.clone();
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Finds the cycle of the states that 'step' produces from 'initial', using Brent's
+/// algorithm, which keeps only two states and usually takes fewer steps than Floyd's.
+/// Loops forever if there is no cycle.
+pub fn brent<S: Clone + PartialEq>(initial: &S, step: impl Fn(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Keep the hare 'length' steps ahead until they meet at the start of the cycle
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = (0..length).fold(initial.clone(), |state, _| step(&state));
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Finds the cycle of the states that 'step' produces from 'initial' by remembering the
+/// step at which the 'key' of each state was first seen. This takes the fewest steps, and
+/// the key may leave out parts of the state that do not affect later steps, like a counter.
+/// Loops forever if there is no cycle.
+pub fn find_cycle<S, K: Hash + Eq>(initial: S, key: impl Fn(&S) -> K, step: impl Fn(&S) -> S) -> Cycle {
+    record(initial, u64::MAX, key, step, |_| ()).1.expect("no cycle")
+}
+
+/// Steps from 'initial' until step 'steps' or until the 'key' of a state repeats, and
+/// returns the 'metric' of each state seen, and the cycle if one was found.
+fn record<S, K: Hash + Eq, M>(
+    initial: S,
+    steps: u64,
+    key: impl Fn(&S) -> K,
+    step: impl Fn(&S) -> S,
+    metric: impl Fn(&S) -> M,
+) -> (Vec<M>, Option<Cycle>) {
+    let mut seen: HashMap<K, u64> = HashMap::new();
+    let mut values: Vec<M> = Vec::new();
+    let mut state = initial;
+    for i in 0.. {
+        values.push(metric(&state));
+        if let Some(first) = seen.insert(key(&state), i) {
+            return (values, Some(Cycle { start: first, length: i - first }));
+        }
+        if i == steps {
+            break;
+        }
+        state = step(&state);
+    }
+    (values, None)
+}
+
+/// Returns the 'metric' of the state after 'steps' steps from 'initial', stepping only
+/// until the 'key' of a state repeats. The metric must be a function of the key, like the
+/// top of the stacks of crates after a list of moves repeated forever.
+pub fn fast_forward<S, K: Hash + Eq, M>(
+    initial: S,
+    steps: u64,
+    key: impl Fn(&S) -> K,
+    step: impl Fn(&S) -> S,
+    metric: impl Fn(&S) -> M,
+) -> M {
+    let (mut values, cycle) = record(initial, steps, key, step, metric);
+    let n = cycle.map_or(steps, |cycle| cycle.equivalent_step(steps));
+    values.swap_remove(n as usize)
+}
+
+/// Returns the 'metric' of the state after 'steps' steps from 'initial', like
+/// 'fast_forward', for a metric that grows by the same amount in every cycle and is
+/// left out of the key, like the height of a tower of falling blocks.
+pub fn fast_forward_linear<S, K: Hash + Eq>(
+    initial: S,
+    steps: u64,
+    key: impl Fn(&S) -> K,
+    step: impl Fn(&S) -> S,
+    metric: impl Fn(&S) -> i64,
+) -> i64 {
+    let (values, cycle) = record(initial, steps, key, step, metric);
+    match cycle {
+        Some(cycle) => cycle.extrapolate(steps, &values),
+        None => values[steps as usize],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day05::{get_top_of_stacks, Move};
+    use crate::property::{check, no_shrink};
+    use crate::random::Random;
+
+    /// A counter that enters a cycle of length 3 after 5 steps.
+    fn count(n: &u64) -> u64 {
+        if *n < 7 { n + 1 } else { 5 }
+    }
+
+    #[test]
+    fn test_can_find_cycle() {
+        let expected = Cycle { start: 5, length: 3 };
+        assert_eq!(floyd(&0, count), expected);
+        assert_eq!(brent(&0, count), expected);
+        assert_eq!(find_cycle(0, |n| *n, count), expected);
+        assert_eq!(find_cycle(6, |n| *n, count), Cycle { start: 0, length: 3 });
+
+        let fixed = |n: &u64| *n;
+        assert_eq!(floyd(&4, fixed), Cycle { start: 0, length: 1 });
+        assert_eq!(brent(&4, fixed), Cycle { start: 0, length: 1 });
+    }
+
+    #[test]
+    fn test_can_get_equivalent_step() {
+        let cycle = Cycle { start: 5, length: 3 };
+        let steps: Vec<u64> = (0..12).map(|n| cycle.equivalent_step(n)).collect();
+        assert_eq!(steps, vec![0, 1, 2, 3, 4, 5, 6, 7, 5, 6, 7, 5]);
+    }
+
+    #[test]
+    fn test_can_fast_forward_crane() {
+        let moves: Vec<Move> = ["move 1 from 1 to 2", "move 1 from 2 to 3", "move 1 from 3 to 1"]
+            .iter()
+            .map(|s| Move::from(s))
+            .collect();
+        let initial = (vec![vec![], vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']], 0);
+        let step = |(stacks, i): &(Vec<Vec<char>>, usize)| {
+            let mut stacks = stacks.clone();
+            moves[*i].execute_1(&mut stacks);
+            (stacks, (i + 1) % moves.len())
+        };
+        let top = |(stacks, _): &(Vec<Vec<char>>, usize)| get_top_of_stacks(stacks);
+
+        let mut state = initial.clone();
+        for n in 0..40 {
+            assert_eq!(fast_forward(initial.clone(), n, |s| s.clone(), step, top), top(&state));
+            state = step(&state);
+        }
+        let cycle = find_cycle(initial.clone(), |s| s.clone(), step);
+        let equivalent = fast_forward(initial.clone(), cycle.equivalent_step(1_000_000_000), |s| s.clone(), step, top);
+        assert_eq!(fast_forward(initial, 1_000_000_000, |s| s.clone(), step, top), equivalent);
+    }
+
+    #[test]
+    fn test_can_fast_forward_linear_metric() {
+        // A tower that grows by 3, 2, 3, 2, ... while its phase is 0, 1, 2, 1, 2, ...
+        let step = |(phase, height): &(usize, i64)| match phase {
+            0 => (1, height + 3),
+            1 => (2, height + 2),
+            _ => (1, height + 3),
+        };
+        let initial = (0, 1);
+        assert_eq!(fast_forward_linear(initial, 0, |s| s.0, step, |s| s.1), 1);
+        assert_eq!(fast_forward_linear(initial, 1, |s| s.0, step, |s| s.1), 4);
+        assert_eq!(fast_forward_linear(initial, 5, |s| s.0, step, |s| s.1), 4 + 5 + 5);
+        assert_eq!(fast_forward_linear(initial, 1_000_000_001, |s| s.0, step, |s| s.1), 4 + 5 * 500_000_000);
+    }
+
+    fn random_function(random: &mut Random, size: usize) -> (Vec<usize>, usize) {
+        let n = random.range(1, size as i64 + 1) as usize;
+        let f: Vec<usize> = (0..n).map(|_| random.index(n)).collect();
+        (f, random.index(n))
+    }
+
+    #[test]
+    fn property_cycle_detectors_agree() {
+        check(random_function, no_shrink, |(f, start)| {
+            let step = |x: &usize| f[*x];
+            let cycle = find_cycle(*start, |x| *x, step);
+            floyd(start, step) == cycle && brent(start, step) == cycle
+                && (0..3 * f.len() as u64).all(|n| {
+                    let run = |n: u64| (0..n).fold(*start, |x, _| step(&x));
+                    run(n) == run(cycle.equivalent_step(n))
+                })
+        });
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{groups, sum_rows, to_vector_of_i32};
+use crate::input::puzzle;
+use crate::solution::Solution;
+
+pub fn read_input() -> Vec<Vec<i32>> {
+    parse_input(&puzzle(1))
+}
+
+pub fn parse_input(input: &str) -> Vec<Vec<i32>> {
+    groups(input.as_bytes())
+        .map(|group| to_vector_of_i32(&group))
+        .collect()
+}
+
+#[derive(Default)]
+pub struct Day01 {
+    calories_per_elf: Vec<i32>,
+}
+
+impl Solution for Day01 {
+    fn parse(&mut self, input: &str) {
+        self.calories_per_elf = sum_rows(&parse_input(input));
+        self.calories_per_elf.sort();
+    }
+
+    fn part_1(&self) -> String {
+        self.calories_per_elf.iter()
+            .max()
+            .expect("Empty vector")
+            .to_string()
+    }
+
+    fn part_2(&self) -> String {
+        self.calories_per_elf.iter()
+            .rev()
+            .take(3)
+            .sum::<i32>()
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_read_input() {
+        let result = read_input();
+        assert_eq!(result[0], vec![18313, 2404, 10479]);
+    }
+
+    #[test]
+    fn run_part_1_with_puzzle_input() {
+        let calories_per_elf = sum_rows(&read_input());
+        let max_calories = calories_per_elf.iter()
+            .max()
+            .expect("Empty vector");
+        assert_eq!(*max_calories, 71924);
+    }
+
+    #[test]
+    fn run_part_2_with_puzzle_input() {
+        let mut calories_per_elf = sum_rows(&read_input());
+        calories_per_elf.sort();
+        let sum_calories: i32 = calories_per_elf.iter()
+            .rev()
+            .take(3)
+            .sum();
+        assert_eq!(sum_calories, 210406);
+    }
+}
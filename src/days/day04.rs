@@ -0,0 +1,161 @@
+use crate::{records, to_i32};
+use crate::input::puzzle;
+use crate::solution::Solution;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Interval {
+    from: i32,
+    to: i32,
+}
+
+impl Interval {
+    pub fn from(s: &str) -> Self {
+        let v: Vec<i32> = s.split("-").map(to_i32).collect();
+        Interval {
+            from: v[0],
+            to: v[1],
+        }
+    }
+
+    pub fn contains(&self, other: &Interval) -> bool {
+        self.from <= other.from && self.to >= other.to
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.from <= other.to && self.to >= other.from
+    }
+}
+
+pub fn to_intervals(strings: &[String]) -> Vec<Interval> {
+    strings.iter().map(|s| Interval::from(s)).collect::<Vec<Interval>>()
+}
+
+pub fn read_input() -> Vec<Vec<String>> {
+    records(puzzle(4).as_bytes(), ",").collect()
+}
+
+#[derive(Default)]
+pub struct Day04 {
+    pairs: Vec<Vec<Interval>>,
+}
+
+impl Solution for Day04 {
+    fn parse(&mut self, input: &str) {
+        self.pairs = records(input.as_bytes(), ",")
+            .map(|record| to_intervals(&record))
+            .collect();
+    }
+
+    fn part_1(&self) -> String {
+        self.pairs.iter()
+            .filter(|i| i[0].contains(&i[1]) || i[1].contains(&i[0]))
+            .count()
+            .to_string()
+    }
+
+    fn part_2(&self) -> String {
+        self.pairs.iter()
+            .filter(|i| i[0].overlaps(&i[1]))
+            .count()
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::property::{check, shrink_i64};
+    use crate::random::Random;
+    use super::*;
+
+    #[test]
+    fn test_can_read_input() {
+        let result = read_input();
+        assert_eq!(result[0], vec!["98-99", "3-97"]);
+    }
+
+    #[test]
+    fn test_can_create_interval() {
+        assert_eq!(Interval::from("7-345"), Interval { from: 7, to: 345 });
+        assert_eq!(Interval::from("100-100"), Interval { from: 100, to: 100 });
+    }
+
+    #[test]
+    fn test_contains() {
+        assert!(Interval { from: 7, to: 15 }.contains(&Interval { from: 7, to: 14 }));
+        assert!(Interval { from: 1, to: 8 }.contains(&Interval { from: 7, to: 8 }));
+        assert!(!Interval { from: 1, to: 8 }.contains(&Interval { from: 7, to: 9 }));
+        assert!(!Interval { from: 23, to: 88 }.contains(&Interval { from: 90, to: 999 }));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        assert!(Interval { from: 7, to: 15 }.overlaps(&Interval { from: 7, to: 14 }));
+        assert!(Interval { from: 1, to: 8 }.overlaps(&Interval { from: 7, to: 8 }));
+        assert!(Interval { from: 4, to: 5 }.overlaps(&Interval { from: 3, to: 8 }));
+        assert!(Interval { from: 1, to: 8 }.overlaps(&Interval { from: 7, to: 9 }));
+        assert!(Interval { from: 4, to: 28 }.overlaps(&Interval { from: 28, to: 38 }));
+        assert!(Interval { from: 4, to: 28 }.overlaps(&Interval { from: 1, to: 5 }));
+        assert!(!Interval { from: 23, to: 88 }.overlaps(&Interval { from: 90, to: 999 }));
+        assert!(!Interval { from: 15, to: 17 }.overlaps(&Interval { from: 3, to: 14 }));
+        assert!(!Interval { from: 159, to: 217 }.overlaps(&Interval { from: 158, to: 158 }));
+    }
+
+    #[test]
+    fn run_part_1_with_puzzle_input() {
+        let count = read_input().iter()
+            .map(|record| to_intervals(record))
+            .filter(|i| i[0].contains(&i[1]) || i[1].contains(&i[0]))
+            .count();
+        assert_eq!(count, 459);
+    }
+
+    #[test]
+    fn run_part_2_with_puzzle_input() {
+        let count = read_input().iter()
+            .map(|record| to_intervals(record))
+            .filter(|i| i[0].overlaps(&i[1]))
+            .count();
+        assert_eq!(count, 779);
+    }
+
+    fn random_interval(random: &mut Random, size: usize) -> Interval {
+        let from = random.range(0, size as i64) as i32;
+        let to = random.range(from as i64, size as i64) as i32;
+        Interval { from, to }
+    }
+
+    fn shrink_interval(interval: &Interval) -> Vec<Interval> {
+        shrink_i64(&(interval.to as i64)).into_iter()
+            .map(|to| Interval { from: interval.from.min(to as i32), to: to as i32 })
+            .chain(shrink_i64(&(interval.from as i64)).into_iter().map(|from| Interval { from: from as i32, to: interval.to }))
+            .collect()
+    }
+
+    fn random_pair(random: &mut Random, size: usize) -> (Interval, Interval) {
+        (random_interval(random, size), random_interval(random, size))
+    }
+
+    fn shrink_pair(pair: &(Interval, Interval)) -> Vec<(Interval, Interval)> {
+        shrink_interval(&pair.0).into_iter().map(|i| (i, pair.1.clone()))
+            .chain(shrink_interval(&pair.1).into_iter().map(|i| (pair.0.clone(), i)))
+            .collect()
+    }
+
+    #[test]
+    fn property_contains_implies_overlaps() {
+        check(random_pair, shrink_pair, |(a, b)| !a.contains(b) || a.overlaps(b));
+    }
+
+    #[test]
+    fn property_overlaps_is_symmetric() {
+        check(random_pair, shrink_pair, |(a, b)| a.overlaps(b) == b.overlaps(a));
+    }
+
+    #[test]
+    fn property_overlaps_agrees_with_sections() {
+        check(random_pair, shrink_pair, |(a, b)| {
+            let shares_section = (a.from..=a.to).any(|section| b.from <= section && section <= b.to);
+            a.overlaps(b) == shares_section
+        });
+    }
+}
@@ -0,0 +1,318 @@
+use lazy_static::lazy_static;
+use regex::{Regex};
+use crate::to_i32;
+use crate::input::puzzle;
+use crate::solution::Solution;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Move {
+    pub fn from(s: &str) -> Self {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^move ([0-9]+) from ([1-9]) to ([1-9])$").unwrap();
+        }
+        match RE.captures(s) {
+            Some(capture) => {
+                Move {
+                    count: to_i32(&capture[1]) as usize,
+                    from: to_i32(&capture[2]) as usize,
+                    to: to_i32(&capture[3]) as usize,
+                }
+            }
+            None => panic!("failed to parse move: {}", s)
+        }
+    }
+
+    /// Executes a move by actually moving crates from one stack to another.
+    /// This method moves one crate at a time.
+    pub fn execute_1(&self, stacks: &mut [Vec<char>]) {
+        for _n in 0..self.count {
+            match stacks[self.from].pop() {
+                Some(c) => stacks[self.to].push(c),
+                None => panic!("stack {} is exhausted", self.from)
+            }
+        }
+    }
+
+    /// Executes a move by actually moving crates from one stack to another.
+    /// This method moves all crates at once.
+    pub fn execute_2(&self, stacks: &mut [Vec<char>]) {
+        let len = stacks[self.from].len();
+        let mut sub_stack: Vec<char> = stacks[self.from].drain(len - self.count..len).collect();
+        stacks[self.to].append(&mut sub_stack);
+    }
+}
+
+pub fn get_top_of_stacks(stacks: &[Vec<char>]) -> String {
+    stacks.iter()
+        .filter(|s| !s.is_empty())
+        .map(|s| s[s.len() - 1])
+        .collect()
+}
+
+/// Splits the puzzle input into the lines that draw the stacks,
+/// and the lines that describe the moves.
+pub fn split_input(input: &str) -> (Vec<&str>, Vec<&str>) {
+    let mut lines = input.lines();
+    let drawing = lines.by_ref()
+        .take_while(|line| !line.trim().is_empty())
+        .collect();
+    let moves = lines
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    (drawing, moves)
+}
+
+/// Parses the drawing of the stacks. The last line of the drawing holds the stack
+/// numbers, and the lines above it hold the crates, with the top crate first.
+/// The returned vector has an unused stack at index 0, so stacks can be indexed
+/// by their numbers.
+pub fn parse_stacks(drawing: &[&str]) -> Vec<Vec<char>> {
+    let (numbers, crates) = drawing.split_last().expect("drawing is not empty");
+    let count = numbers.split_whitespace().count();
+
+    let mut stacks: Vec<Vec<char>> = vec![vec![]; count + 1];
+    for line in crates.iter().rev() {
+        let chars: Vec<char> = line.chars().collect();
+        for (i, stack) in stacks.iter_mut().enumerate().skip(1) {
+            match chars.get(4 * i - 3) {
+                Some(c) if c.is_ascii_alphabetic() => stack.push(*c),
+                _ => {}
+            }
+        }
+    }
+    stacks
+}
+
+pub fn read_stacks() -> Vec<Vec<char>> {
+    parse_stacks(&split_input(&puzzle(5)).0)
+}
+
+pub fn read_input() -> Vec<String> {
+    split_input(&puzzle(5)).1.iter()
+        .map(|s| String::from(*s))
+        .collect()
+}
+
+#[derive(Default)]
+pub struct Day05 {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+impl Day05 {
+    fn execute(&self, execute: fn(&Move, &mut [Vec<char>])) -> String {
+        let mut stacks = self.stacks.clone();
+        self.moves.iter().for_each(|mv| execute(mv, &mut stacks));
+        get_top_of_stacks(&stacks)
+    }
+}
+
+impl Solution for Day05 {
+    fn parse(&mut self, input: &str) {
+        let (drawing, moves) = split_input(input);
+        self.stacks = parse_stacks(&drawing);
+        self.moves = moves.iter().map(|s| Move::from(s)).collect();
+    }
+
+    fn part_1(&self) -> String {
+        self.execute(Move::execute_1)
+    }
+
+    fn part_2(&self) -> String {
+        self.execute(Move::execute_2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::property::{check, shrink_usize, shrink_vec};
+    use crate::random::Random;
+    use super::*;
+
+    #[test]
+    fn test_can_read_input() {
+        let result = read_input();
+        assert_eq!(result[0], "move 8 from 7 to 1");
+    }
+
+    #[test]
+    fn test_can_read_stacks() {
+        let stacks = read_stacks();
+        assert_eq!(stacks[1], vec!['H', 'C', 'R']);
+        assert_eq!(stacks[9], vec!['L', 'D', 'T', 'R', 'H', 'P', 'F', 'S']);
+    }
+
+    #[test]
+    fn test_can_parse_stacks() {
+        let drawing = vec![
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+        ];
+        let stacks = parse_stacks(&drawing);
+        assert_eq!(stacks, vec![vec![], vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+    }
+
+    #[test]
+    fn test_can_parse_move() {
+        assert_eq!(Move::from("move 8 from 7 to 1"), Move { count: 8, from: 7, to: 1 });
+    }
+
+    #[test]
+    fn test_can_parse_input() {
+        let result: Vec<Move> = read_input().iter()
+            .map(|s| Move::from(s))
+            .collect();
+        assert_eq!(result[0], Move { count: 8, from: 7, to: 1 });
+        assert_eq!(result[1], Move { count: 9, from: 1, to: 9 });
+    }
+
+    #[test]
+    fn test_can_execute_move_1_a() {
+        let mut stacks = read_stacks();
+        Move { count: 0, from: 9, to: 1 }.execute_1(&mut stacks);
+        assert_eq!(stacks[1], vec!['H', 'C', 'R']);
+        assert_eq!(stacks[9], vec!['L', 'D', 'T', 'R', 'H', 'P', 'F', 'S']);
+    }
+
+    #[test]
+    fn test_can_execute_move_1_b() {
+        let mut stacks = read_stacks();
+        Move { count: 2, from: 9, to: 1 }.execute_1(&mut stacks);
+        assert_eq!(stacks[1], vec!['H', 'C', 'R', 'S', 'F']);
+        assert_eq!(stacks[9], vec!['L', 'D', 'T', 'R', 'H', 'P']);
+    }
+
+    #[test]
+    fn test_can_execute_move_1_c() {
+        let mut stacks = read_stacks();
+        Move { count: 3, from: 1, to: 6 }.execute_1(&mut stacks);
+        assert_eq!(stacks[1].len(), 0);
+        assert_eq!(stacks[6], vec!['T', 'H', 'C', 'G', 'R', 'C', 'H']);
+    }
+
+    #[test]
+    fn test_can_execute_move_2_a() {
+        let mut stacks = read_stacks();
+        Move { count: 0, from: 9, to: 1 }.execute_2(&mut stacks);
+        assert_eq!(stacks[1], vec!['H', 'C', 'R']);
+        assert_eq!(stacks[9], vec!['L', 'D', 'T', 'R', 'H', 'P', 'F', 'S']);
+    }
+
+    #[test]
+    fn test_can_execute_move_2_b() {
+        let mut stacks = read_stacks();
+        Move { count: 2, from: 9, to: 1 }.execute_2(&mut stacks);
+        assert_eq!(stacks[1], vec!['H', 'C', 'R', 'F', 'S']);
+        assert_eq!(stacks[9], vec!['L', 'D', 'T', 'R', 'H', 'P']);
+    }
+
+    #[test]
+    fn test_can_execute_move_2_c() {
+        let mut stacks = read_stacks();
+        Move { count: 3, from: 1, to: 6 }.execute_2(&mut stacks);
+        assert_eq!(stacks[1].len(), 0);
+        assert_eq!(stacks[6], vec!['T', 'H', 'C', 'G', 'H', 'C', 'R']);
+    }
+
+    #[test]
+    fn test_can_get_top_of_stacks() {
+        let stacks = read_stacks();
+        assert_eq!(get_top_of_stacks(&stacks), "RFQJBGLCS");
+    }
+
+    #[test]
+    fn run_part_1_with_puzzle_input() {
+        let mut stacks = read_stacks();
+        read_input().iter()
+            .map(|s| Move::from(s))
+            .for_each(|mv| mv.execute_1(&mut stacks));
+        let result = get_top_of_stacks(&stacks);
+        assert_eq!(result, "SHQWSRBDL");
+    }
+
+    #[test]
+    fn run_part_2_with_puzzle_input() {
+        let mut stacks = read_stacks();
+        read_input().iter()
+            .map(|s| Move::from(s))
+            .for_each(|mv| mv.execute_2(&mut stacks));
+        let result = get_top_of_stacks(&stacks);
+        assert_eq!(result, "CDTQZHBRS");
+    }
+
+    fn random_stacks_and_move(random: &mut Random, size: usize) -> (Vec<Vec<char>>, Move) {
+        let count = random.range(1, 9) as usize;
+        let mut stacks: Vec<Vec<char>> = vec![vec![]; count + 1];
+        for stack in stacks.iter_mut().skip(1) {
+            let height = random.range(0, size as i64);
+            stack.extend((0..height).map(|_| (b'A' + random.range(0, 25) as u8) as char));
+        }
+        let from = random.range(1, count as i64) as usize;
+        let to = random.range(1, count as i64) as usize;
+        let count = random.range(0, stacks[from].len() as i64) as usize;
+        (stacks, Move { count, from, to })
+    }
+
+    fn shrink_stacks_and_move((stacks, mv): &(Vec<Vec<char>>, Move)) -> Vec<(Vec<Vec<char>>, Move)> {
+        let smaller_moves = shrink_usize(&mv.count).into_iter()
+            .map(|count| (stacks.clone(), Move { count, ..*mv }));
+        let smaller_stacks = (1..stacks.len())
+            .flat_map(|i| shrink_vec(&stacks[i], |_| vec![]).into_iter().map(move |stack| (i, stack)))
+            .map(|(i, stack)| {
+                let mut stacks = stacks.clone();
+                stacks[i] = stack;
+                (stacks, *mv)
+            });
+        smaller_moves.chain(smaller_stacks)
+            .filter(|(stacks, mv)| mv.count <= stacks[mv.from].len())
+            .collect()
+    }
+
+    fn sorted_crates(stacks: &[Vec<char>]) -> Vec<char> {
+        let mut crates: Vec<char> = stacks.iter().flatten().copied().collect();
+        crates.sort();
+        crates
+    }
+
+    #[test]
+    fn property_execute_1_conserves_crates() {
+        check(random_stacks_and_move, shrink_stacks_and_move, |(stacks, mv)| {
+            let mut moved = stacks.clone();
+            mv.execute_1(&mut moved);
+            sorted_crates(&moved) == sorted_crates(stacks)
+        });
+    }
+
+    #[test]
+    fn property_execute_2_conserves_crates() {
+        check(random_stacks_and_move, shrink_stacks_and_move, |(stacks, mv)| {
+            let mut moved = stacks.clone();
+            mv.execute_2(&mut moved);
+            sorted_crates(&moved) == sorted_crates(stacks)
+        });
+    }
+
+    #[test]
+    fn property_execute_1_reverses_order_of_moved_crates() {
+        check(random_stacks_and_move, shrink_stacks_and_move, |(stacks, mv)| {
+            if mv.from == mv.to {
+                return true;
+            }
+            let (mut moved_1, mut moved_2) = (stacks.clone(), stacks.clone());
+            mv.execute_1(&mut moved_1);
+            mv.execute_2(&mut moved_2);
+            let top_1: Vec<char> = moved_1[mv.to].iter().rev().take(mv.count).copied().collect();
+            let top_2: Vec<char> = moved_2[mv.to].iter().rev().take(mv.count).rev().copied().collect();
+            top_1 == top_2
+        });
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+use crate::lines;
+use crate::input::puzzle;
+use crate::solution::Solution;
+
+pub fn read_input() -> String {
+    lines(puzzle(6).as_bytes()).next().expect("input is not empty")
+}
+
+pub fn find_first_unique_substring(s : &str, len: usize) -> &str {
+    try_find_first_unique_substring(s, len)
+        .unwrap_or_else(|| panic!("found no unique substring with {} letters", len))
+}
+
+/// Returns the first substring of 's' with 'len' different letters, or None if there
+/// is none. The last substring ends at the end of 's'.
+pub fn try_find_first_unique_substring(s : &str, len: usize) -> Option<&str> {
+    (0..(s.len() + 1).saturating_sub(len))
+        .map(|i| &s[i..i + len])
+        .find(|substring| unique(substring))
+}
+
+fn unique(s: &str) -> bool {
+    let set: HashSet<char> = HashSet::from_iter(s.chars());
+    set.len() == s.len()
+}
+
+pub fn find_end_of_marker(input: &str, len: usize) -> usize {
+    let marker = find_first_unique_substring(input, len);
+    input.find(marker).expect("should find unique substring") + len
+}
+
+/// Returns the end of the first marker with 'len' letters, or None if there is no marker.
+pub fn try_find_end_of_marker(input: &str, len: usize) -> Option<usize> {
+    let marker = try_find_first_unique_substring(input, len)?;
+    input.find(marker).map(|start| start + len)
+}
+
+#[derive(Default)]
+pub struct Day06 {
+    datastream: String,
+}
+
+impl Solution for Day06 {
+    fn parse(&mut self, input: &str) {
+        self.datastream = String::from(input.trim());
+    }
+
+    fn part_1(&self) -> String {
+        find_end_of_marker(&self.datastream, 4).to_string()
+    }
+
+    fn part_2(&self) -> String {
+        find_end_of_marker(&self.datastream, 14).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::find_marker_naively;
+    use crate::property::{check, random_string, shrink_string};
+    use super::*;
+
+    #[test]
+    fn test_can_read_input() {
+        let result = read_input();
+        assert!(result.starts_with("gzbzw"));
+    }
+
+    #[test]
+    fn test_can_find_end_of_marker() {
+        assert_eq!(find_end_of_marker("bvwbjplbgvbhsrlpgdmjqwftvncz", 4), 5);
+        assert_eq!(find_end_of_marker("nppdvjthqldpwncqszvftbrmjlhg", 4), 6);
+        assert_eq!(find_end_of_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4), 10);
+        assert_eq!(find_end_of_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4), 11);
+
+        assert_eq!(find_end_of_marker("bvwbjplbgvbhsrlpgdmjqwftvncz", 14), 23);
+        assert_eq!(find_end_of_marker("nppdvjthqldpwncqszvftbrmjlhg", 14), 23);
+        assert_eq!(find_end_of_marker("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14), 29);
+        assert_eq!(find_end_of_marker("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14), 26);
+    }
+
+    #[test]
+    fn run_part_1_with_puzzle_input() {
+        let input = read_input();
+        assert_eq!(find_end_of_marker(&input, 4), 1262);
+    }
+
+    #[test]
+    fn run_part_2_with_puzzle_input() {
+        let input = read_input();
+        assert_eq!(find_end_of_marker(&input, 14), 3444);
+    }
+
+    #[test]
+    fn test_can_find_marker_at_end() {
+        assert_eq!(find_end_of_marker("aaabcd", 4), 6);
+        assert_eq!(find_end_of_marker("abcd", 4), 4);
+        assert_eq!(find_end_of_marker("aabcdefghijklmn", 14), 15);
+    }
+
+    #[test]
+    fn test_can_find_no_marker() {
+        assert_eq!(try_find_end_of_marker("aaabcd", 4), Some(6));
+        assert_eq!(try_find_end_of_marker("aaabc", 4), None);
+        assert_eq!(try_find_end_of_marker("abc", 4), None);
+        assert_eq!(try_find_end_of_marker("", 4), None);
+    }
+
+    #[test]
+    fn test_can_find_no_unique_substring() {
+        assert_eq!(try_find_first_unique_substring("aaabcd", 4), Some("abcd"));
+        assert_eq!(try_find_first_unique_substring("abcabc", 4), None);
+        assert_eq!(try_find_first_unique_substring("ab", 4), None);
+        assert_eq!(try_find_first_unique_substring("", 4), None);
+    }
+
+    #[test]
+    fn property_marker_agrees_with_naive_scan() {
+        for len in [4, 14] {
+            check(|r, size| random_string(r, size, &"abcdefghijklmnop"[..len + 1]), shrink_string, |s| {
+                try_find_end_of_marker(s, len) == find_marker_naively(&s.chars().collect::<Vec<char>>(), len)
+            });
+        }
+    }
+}
@@ -0,0 +1,816 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+use crate::{lines, to_i64, to_vector_of_str};
+use crate::input::puzzle;
+use crate::solution::Solution;
+
+pub fn read_input() -> Vec<String> {
+    lines(puzzle(7).as_bytes()).collect()
+}
+
+/// The order in which a transcript visits the directories.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Traversal {
+    /// Enters each subdirectory right after listing its parent, and returns with `cd ..`.
+    DepthFirst,
+    /// Lists all directories at one depth before the next, going there from the root.
+    BreadthFirst,
+}
+
+/// A directory in the file system described by a transcript.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Directory {
+    pub name: String,
+    pub parent: Option<usize>,
+    /// The subdirectories, by name, as indexes into the directories of the file system.
+    pub dirs: BTreeMap<String, usize>,
+    /// The sizes of the files, by name.
+    pub files: BTreeMap<String, i64>,
+    /// True if the contents of this directory have been listed.
+    pub listed: bool,
+    /// True if the transcript enters this directory, lists it, or names it in a listing.
+    pub mentioned: bool,
+}
+
+/// The file system described by a transcript of shell commands. The root is the first
+/// directory, and every directory comes after its parent.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct FileSystem {
+    pub dirs: Vec<Directory>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        let root = Directory {
+            name: String::new(),
+            parent: None,
+            dirs: BTreeMap::new(),
+            files: BTreeMap::new(),
+            listed: false,
+            mentioned: false,
+        };
+        FileSystem { dirs: vec![root] }
+    }
+}
+
+impl FileSystem {
+    /// Replays a transcript of `cd` and `ls` commands, starting in the root directory.
+    /// `cd` takes absolute and relative paths with any number of components, and `cd ..`
+    /// in the root stays in the root. Listing a directory again does not count its files
+    /// twice, and directories that are never listed are still part of the file system.
+    /// Panics on malformed commands, like `cd` without a path, and on malformed listings.
+    pub fn from_transcript(transcript: &[&str]) -> Self {
+        let mut fs = FileSystem::default();
+        let mut current = 0;
+        for line in transcript {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts.as_slice() {
+                ["$", "cd", path] => current = fs.change_dir(current, path),
+                ["$", "ls"] => {
+                    fs.dirs[current].listed = true;
+                    fs.dirs[current].mentioned = true;
+                }
+                ["$", ..] => panic!("Invalid command: {}", line),
+                ["dir", name] => {
+                    let dir = fs.get_or_create_dir(current, name);
+                    fs.dirs[dir].mentioned = true;
+                }
+                [size, name] if size.parse::<i64>().is_ok() => {
+                    fs.dirs[current].files.insert(String::from(*name), to_i64(size));
+                }
+                _ => panic!("Not a command or a listing: {}", line),
+            }
+        }
+        fs
+    }
+
+    /// Returns the subdirectory 'name' of 'parent', which is created if it does not exist.
+    fn get_or_create_dir(&mut self, parent: usize, name: &str) -> usize {
+        if let Some(dir) = self.dirs[parent].dirs.get(name) {
+            return *dir;
+        }
+        let dir = self.dirs.len();
+        self.dirs.push(Directory {
+            name: String::from(name),
+            parent: Some(parent),
+            dirs: BTreeMap::new(),
+            files: BTreeMap::new(),
+            listed: false,
+            mentioned: false,
+        });
+        self.dirs[parent].dirs.insert(String::from(name), dir);
+        dir
+    }
+
+    /// Returns the directory reached by changing from 'current' to 'path'. All
+    /// directories that are passed through are mentioned.
+    fn change_dir(&mut self, current: usize, path: &str) -> usize {
+        let mut dir = current;
+        if path.starts_with('/') {
+            dir = 0;
+            self.dirs[dir].mentioned = true;
+        }
+        for component in path.split('/').filter(|c| !c.is_empty() && *c != ".") {
+            dir = match component {
+                ".." => self.dirs[dir].parent.unwrap_or(0),
+                name => self.get_or_create_dir(dir, name),
+            };
+            self.dirs[dir].mentioned = true;
+        }
+        dir
+    }
+
+    /// Returns the "/" separated path of 'dir', e.g. "/a/e".
+    pub fn get_path(&self, dir: usize) -> String {
+        match self.dirs[dir].parent {
+            None => String::from("/"),
+            Some(0) => format!("/{}", self.dirs[dir].name),
+            Some(parent) => format!("{}/{}", self.get_path(parent), self.dirs[dir].name),
+        }
+    }
+
+    /// Returns the total size of the files in each directory, including its subdirectories.
+    pub fn get_total_sizes(&self) -> Vec<i64> {
+        let mut sizes: Vec<i64> = self.dirs.iter().map(|dir| dir.files.values().sum()).collect();
+        // Children come after their parents, so they are complete before they are added
+        for dir in (1..self.dirs.len()).rev() {
+            let parent = self.dirs[dir].parent.expect("only the root has no parent");
+            sizes[parent] += sizes[dir];
+        }
+        sizes
+    }
+
+    /// Returns the sizes of all mentioned directories by path.
+    pub fn get_sizes(&self) -> HashMap<String, i64> {
+        self.get_total_sizes().into_iter()
+            .enumerate()
+            .filter(|(dir, _)| self.dirs[*dir].mentioned)
+            .map(|(dir, size)| (self.get_path(dir), size))
+            .collect()
+    }
+
+    /// Returns the paths of the mentioned directories whose sizes are unknown, because
+    /// they, or one of their subdirectories, have never been listed.
+    pub fn get_unknown_sizes(&self) -> Vec<String> {
+        let mut known: Vec<bool> = self.dirs.iter().map(|dir| dir.listed).collect();
+        for dir in (1..self.dirs.len()).rev() {
+            let parent = self.dirs[dir].parent.expect("only the root has no parent");
+            known[parent] &= known[dir];
+        }
+        let mut paths: Vec<String> = (0..self.dirs.len())
+            .filter(|dir| self.dirs[*dir].mentioned && !known[*dir])
+            .map(|dir| self.get_path(dir))
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    /// Returns the file system as JSON. Each directory and file is an object with a
+    /// "name", a "type" and a "size", and directories also have "children", the
+    /// subdirectories first. The names are sorted, and the root is named "/".
+    pub fn to_json(&self) -> String {
+        let sizes = self.get_total_sizes();
+        let mut json = String::new();
+        self.write_json(&mut json, 0, &sizes);
+        json + "\n"
+    }
+
+    fn write_json(&self, json: &mut String, dir: usize, sizes: &[i64]) {
+        let name = if dir == 0 { "/" } else { &self.dirs[dir].name };
+        *json += &format!("{{\"name\":{},\"type\":\"dir\",\"size\":{},\"children\":[", to_json_string(name), sizes[dir]);
+        let mut first = true;
+        for child in self.dirs[dir].dirs.values() {
+            if !first { json.push(','); }
+            first = false;
+            self.write_json(json, *child, sizes);
+        }
+        for (name, size) in &self.dirs[dir].files {
+            if !first { json.push(','); }
+            first = false;
+            *json += &format!("{{\"name\":{},\"type\":\"file\",\"size\":{}}}", to_json_string(name), size);
+        }
+        *json += "]}";
+    }
+
+    /// Returns the file system as the output of `du -a` in its root: one line with the
+    /// size and the path of each file and directory, the contents of a directory before
+    /// the directory itself. Sizes are counted in blocks of 'block_size' bytes, rounded
+    /// up, like `du -a` counts in blocks of 1024 bytes, and `du -ab` in single bytes.
+    ///
+    /// A transcript has no disk, so sizes are the apparent sizes of the files, as with
+    /// `du --apparent-size`, and a directory counts the bytes of its contents before
+    /// rounding. Unlike real directories, directories have no size of their own, so
+    /// compare the files, or subtract the size of the directory entries.
+    pub fn to_du(&self, block_size: i64) -> String {
+        let sizes = self.get_total_sizes();
+        let mut du = String::new();
+        self.write_du(&mut du, 0, ".", &sizes, block_size);
+        du
+    }
+
+    fn write_du(&self, du: &mut String, dir: usize, path: &str, sizes: &[i64], block_size: i64) {
+        let blocks = |size: i64| (size + block_size - 1) / block_size;
+        for (name, child) in &self.dirs[dir].dirs {
+            self.write_du(du, *child, &format!("{}/{}", path, name), sizes, block_size);
+        }
+        for (name, size) in &self.dirs[dir].files {
+            *du += &format!("{}\t{}/{}\n", blocks(*size), path, name);
+        }
+        *du += &format!("{}\t{}\n", blocks(sizes[dir]), path);
+    }
+
+    /// Creates the directories of the file system in 'root', with sparse files of the
+    /// recorded sizes, so that real tools like `du` and `find` can inspect it. Fails if
+    /// a name could escape 'root', or if a file already exists.
+    pub fn write_skeleton(&self, root: &Path) -> io::Result<()> {
+        fs::create_dir_all(root)?;
+        for (dir, directory) in self.dirs.iter().enumerate() {
+            let path = root.join(self.get_path(dir).trim_start_matches('/'));
+            if dir != 0 {
+                check_name(&directory.name)?;
+                fs::create_dir_all(&path)?;
+            }
+            for (name, size) in &directory.files {
+                check_name(name)?;
+                File::create_new(path.join(name))?.set_len(*size as u64)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the directory tree at 'root' from disk, with the sizes of the regular files
+    /// in it. Symbolic links and other special files are skipped, and are not followed.
+    /// Fails if a name is not UTF-8, or contains whitespace, which transcripts cannot show.
+    pub fn from_dir(root: &Path) -> io::Result<Self> {
+        let mut file_system = FileSystem::default();
+        file_system.dirs[0].mentioned = true;
+        file_system.read_dir(0, root)?;
+        Ok(file_system)
+    }
+
+    fn read_dir(&mut self, dir: usize, path: &Path) -> io::Result<()> {
+        self.dirs[dir].listed = true;
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            let name = entry.file_name().into_string()
+                .map_err(|name| io::Error::new(io::ErrorKind::InvalidData, format!("Not a UTF-8 name: {:?}", name)))?;
+            if name.contains(char::is_whitespace) {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, format!("Name contains whitespace: {}", name)));
+            }
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                let child = self.get_or_create_dir(dir, &name);
+                self.dirs[child].mentioned = true;
+                self.read_dir(child, &entry.path())?;
+            } else if file_type.is_file() {
+                self.dirs[dir].files.insert(name, entry.metadata()?.len() as i64);
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a terminal transcript in the puzzle format that lists every directory exactly
+    /// once, visiting the directories in the order of 'traversal', and the names in order.
+    pub fn to_transcript(&self, traversal: Traversal) -> Vec<String> {
+        let mut lines = vec![String::from("$ cd /")];
+        match traversal {
+            Traversal::DepthFirst => self.write_listing(&mut lines, 0, true),
+            Traversal::BreadthFirst => {
+                let mut queue = VecDeque::from([0]);
+                while let Some(dir) = queue.pop_front() {
+                    if dir != 0 {
+                        lines.push(String::from("$ cd /"));
+                        for component in self.get_path(dir).split('/').filter(|c| !c.is_empty()) {
+                            lines.push(format!("$ cd {}", component));
+                        }
+                    }
+                    self.write_listing(&mut lines, dir, false);
+                    queue.extend(self.dirs[dir].dirs.values());
+                }
+            }
+        }
+        lines
+    }
+
+    /// Writes the listing of 'dir', followed by the listings of its subdirectories if 'recurse'.
+    fn write_listing(&self, lines: &mut Vec<String>, dir: usize, recurse: bool) {
+        lines.push(String::from("$ ls"));
+        lines.extend(self.dirs[dir].dirs.keys().map(|name| format!("dir {}", name)));
+        lines.extend(self.dirs[dir].files.iter().map(|(name, size)| format!("{} {}", size, name)));
+        if recurse {
+            for (name, child) in &self.dirs[dir].dirs {
+                lines.push(format!("$ cd {}", name));
+                self.write_listing(lines, *child, true);
+                lines.push(String::from("$ cd .."));
+            }
+        }
+    }
+}
+
+/// Returns an error if 'name' is not a single, normal path component.
+fn check_name(name: &str) -> io::Result<()> {
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Not a valid name: {}", name)));
+    }
+    Ok(())
+}
+
+/// Returns 's' as a quoted JSON string.
+fn to_json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            c if c.is_control() => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+    json + "\""
+}
+
+/// Returns the sizes of all directories mentioned in 'transcript' by path.
+pub fn get_sizes(transcript: &[&str]) -> HashMap<String, i64> {
+    FileSystem::from_transcript(transcript).get_sizes()
+}
+
+/// Returns the sum of the sizes of all directories with a size of at most 'limit'.
+pub fn sum_of_small_directories(sizes: &HashMap<String, i64>, limit: i64) -> i64 {
+    sizes.values()
+        .filter(|size| **size <= limit)
+        .sum()
+}
+
+/// Returns the size of the smallest directory that, if deleted, would free up enough
+/// space to reach 'required_space' on a disk with a size of 'total_space'.
+pub fn size_of_smallest_directory_to_delete(sizes: &HashMap<String, i64>, total_space: i64, required_space: i64) -> i64 {
+    let used_space = sizes.get("/").expect("root has a size");
+    let free_space = total_space - used_space;
+    let missing_space = required_space - free_space;
+
+    *sizes.values()
+        .filter(|size| **size >= missing_space)
+        .min()
+        .expect("one directory is big enough")
+}
+
+#[derive(Default)]
+pub struct Day07 {
+    sizes: HashMap<String, i64>,
+}
+
+impl Solution for Day07 {
+    fn parse(&mut self, input: &str) {
+        let lines: Vec<String> = lines(input.as_bytes()).collect();
+        self.sizes = get_sizes(&to_vector_of_str(&lines));
+    }
+
+    fn part_1(&self) -> String {
+        sum_of_small_directories(&self.sizes, 100_000).to_string()
+    }
+
+    fn part_2(&self) -> String {
+        size_of_smallest_directory_to_delete(&self.sizes, 70_000_000, 30_000_000).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+    use crate::generate::{generate_file_system, get_dir_path, get_total_sizes, write_transcript};
+    use crate::property::{check, shrink_usize};
+    use crate::random::Random;
+    use crate::input::example;
+    use super::*;
+
+    #[test]
+    fn test_can_read_input() {
+        let input = read_input();
+        assert_eq!(input[0], "$ cd /");
+        assert_eq!(input[1], "$ ls");
+        assert_eq!(input[2], "dir dpbwg");
+    }
+
+    #[test]
+    fn test_get_size_of_empty() {
+        let vec: Vec<&str> = vec![];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.len(), 0);
+    }
+
+    #[test]
+    fn test_get_size_of_single_cd() {
+        let vec: Vec<&str> = vec!["$ cd /"];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.len(), 1);
+        assert_eq!(sizes.get("/"), Some(&0));
+    }
+
+    #[test]
+    fn test_get_size_of_single_ls() {
+        let vec: Vec<&str> = vec!["$ cd foo", "$ ls"];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.len(), 1);
+        assert_eq!(sizes.get("/foo"), Some(&0));
+    }
+
+    #[test]
+    fn test_get_size_of_ls_single_file() {
+        let vec: Vec<&str> = vec!["$ cd foo", "$ ls", "100 c.dat"];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.len(), 1);
+        assert_eq!(sizes.get("/foo"), Some(&100));
+    }
+
+    #[test]
+    fn test_get_size_of_ls_some_files() {
+        let vec: Vec<&str> = vec!["$ cd /", "$ ls", "100 c.dat", "66 a.txt", "1 a.out"];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.len(), 1);
+        assert_eq!(sizes.get("/"), Some(&167));
+    }
+
+    #[test]
+    fn test_get_size_of_cd_in_and_out() {
+        let vec: Vec<&str> = vec!["$ cd /", "$ cd foo", "$ cd .."];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.len(), 2);
+        assert_eq!(sizes.get("/"), Some(&0));
+        assert_eq!(sizes.get("/foo"), Some(&0));
+    }
+
+    #[test]
+    fn test_get_size_of_cd_in_and_out_with_file() {
+        let vec: Vec<&str> = vec!["$ cd /", "$ cd foo", "$ ls", "8 hej.txt", "$ cd .."];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.len(), 2);
+        assert_eq!(sizes.get("/"), Some(&8));
+        assert_eq!(sizes.get("/foo"), Some(&8));
+    }
+
+    #[test]
+    fn test_get_size_of_cd_in_and_out_with_some_files() {
+        let vec: Vec<&str> = vec![
+            "$ cd /",
+            "$ ls",
+            "dir foo",
+            "5 a.out",
+            "$ cd foo",
+            "$ ls",
+            "8 hej.txt",
+            "3 hoj.txt",
+            "$ cd ..",
+        ];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.len(), 2);
+        assert_eq!(sizes.get("/"), Some(&16));
+        assert_eq!(sizes.get("/foo"), Some(&11));
+    }
+
+    #[test]
+    fn test_get_size_of_cd_in_and_out_with_some_directories() {
+        let vec: Vec<&str> = vec![
+            "$ cd /",
+            "$ ls",
+            "dir foo",
+            "dir bar",
+            "5 a.out",
+            "$ cd foo",
+            "$ ls",
+            "8 hej.txt",
+            "3 hoj.txt",
+            "$ cd ..",
+            "$ cd bar",
+            "$ ls",
+            "dir tee",
+            "90 guh.txt",
+            "$ cd tee",
+            "$ ls",
+            "1 small.txt",
+        ];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.len(), 4);
+        assert_eq!(sizes.get("/"), Some(&107));
+        assert_eq!(sizes.get("/foo"), Some(&11));
+        assert_eq!(sizes.get("/bar"), Some(&91));
+        assert_eq!(sizes.get("/bar/tee"), Some(&1));
+    }
+
+    #[test]
+    fn test_cd_root_resets_to_root() {
+        let vec: Vec<&str> = vec![
+            "$ cd /", "$ ls", "dir a", "1 x",
+            "$ cd a", "$ ls", "2 y",
+            "$ cd /", "$ ls", "dir a", "1 x",
+        ];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.len(), 2);
+        assert_eq!(sizes.get("/"), Some(&3));
+        assert_eq!(sizes.get("/a"), Some(&2));
+    }
+
+    #[test]
+    fn test_cd_with_multiple_components() {
+        let vec: Vec<&str> = vec![
+            "$ cd /a/b/c", "$ ls", "4 x",
+            "$ cd ../d", "$ ls", "5 y",
+            "$ cd ../../..", "$ cd ..", "$ cd a/b", "$ ls", "dir c", "dir d", "6 z",
+        ];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.get("/a/b/c"), Some(&4));
+        assert_eq!(sizes.get("/a/b/d"), Some(&5));
+        assert_eq!(sizes.get("/a/b"), Some(&15));
+        assert_eq!(sizes.get("/"), Some(&15));
+    }
+
+    #[test]
+    fn test_repeated_ls_is_counted_once() {
+        let vec: Vec<&str> = vec!["$ cd /", "$ ls", "7 x", "$ ls", "7 x", "8 y"];
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.get("/"), Some(&15));
+    }
+
+    #[test]
+    fn test_can_report_unknown_sizes() {
+        let vec: Vec<&str> = vec![
+            "$ cd /", "$ ls", "dir a", "dir b", "1 x",
+            "$ cd a", "$ ls", "dir c", "2 y",
+            "$ cd /b", "$ ls", "3 z",
+        ];
+        let fs = FileSystem::from_transcript(&vec);
+        assert_eq!(fs.get_sizes().get("/a/c"), Some(&0));
+        assert_eq!(fs.get_unknown_sizes(), vec!["/", "/a", "/a/c"]);
+
+        let input: Vec<String> = lines(example(7).as_bytes()).collect();
+        assert_eq!(FileSystem::from_transcript(&to_vector_of_str(&input)).get_unknown_sizes(), Vec::<String>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid command: $ cd")]
+    fn test_cannot_replay_cd_without_path() {
+        FileSystem::from_transcript(&["$ cd /", "$ cd"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid command: $ ls -l")]
+    fn test_cannot_replay_unknown_command() {
+        FileSystem::from_transcript(&["$ cd /", "$ ls -l"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Not a command or a listing: big a")]
+    fn test_cannot_replay_listing_without_size() {
+        FileSystem::from_transcript(&["$ cd /", "$ ls", "big a"]);
+    }
+
+    fn example_file_system() -> FileSystem {
+        let input: Vec<String> = lines(example(7).as_bytes()).collect();
+        FileSystem::from_transcript(&to_vector_of_str(&input))
+    }
+
+    #[test]
+    fn test_can_export_json() {
+        let fs = FileSystem::from_transcript(&["$ cd /", "$ ls", "dir a", "3 \"x\\y\"", "$ cd a", "$ ls", "4 z"]);
+        assert_eq!(fs.to_json(), concat!(
+            r#"{"name":"/","type":"dir","size":7,"children":["#,
+            r#"{"name":"a","type":"dir","size":4,"children":[{"name":"z","type":"file","size":4}]},"#,
+            r#"{"name":"\"x\\y\"","type":"file","size":3}]}"#,
+            "\n"));
+        assert!(example_file_system().to_json().starts_with(r#"{"name":"/","type":"dir","size":48381165,"#));
+    }
+
+    #[test]
+    fn test_can_export_du() {
+        let du = example_file_system().to_du(1024);
+        let lines: Vec<&str> = du.lines().collect();
+        assert_eq!(lines.len(), 14);
+        assert_eq!(lines[0], "1\t./a/e/i");
+        assert_eq!(lines[1], "1\t./a/e");
+        assert_eq!(lines[5], "93\t./a");
+        assert_eq!(lines[13], "47248\t.");
+
+        let du = example_file_system().to_du(1);
+        let lines: Vec<&str> = du.lines().collect();
+        assert_eq!(lines.len(), 14);
+        assert_eq!(lines[0], "584\t./a/e/i");
+        assert_eq!(lines[1], "584\t./a/e");
+        assert_eq!(lines[5], "94853\t./a");
+        assert_eq!(lines[13], "48381165\t.");
+    }
+
+    /// Returns the total size of the files in 'path', and in its subdirectories.
+    /// Like 'from_dir', symbolic links are not followed, and only regular files count.
+    fn measure(path: &Path) -> i64 {
+        fs::read_dir(path).unwrap()
+            .map(|entry| entry.unwrap())
+            .map(|entry| {
+                let file_type = entry.file_type().unwrap();
+                if file_type.is_dir() {
+                    measure(&entry.path())
+                } else if file_type.is_file() {
+                    entry.metadata().unwrap().len() as i64
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_can_write_skeleton() {
+        let root = std::env::temp_dir().join(format!("aoc-skeleton-{}", std::process::id()));
+        let file_system = example_file_system();
+        file_system.write_skeleton(&root).unwrap();
+        for (path, size) in file_system.get_sizes() {
+            assert_eq!(measure(&root.join(path.trim_start_matches('/'))), size, "{}", path);
+        }
+        assert_eq!(root.join("d/d.log").metadata().unwrap().len(), 8033020);
+
+        // Real du agrees on the sizes of the files, if it is installed. The files are
+        // sparse, so du must count their apparent sizes.
+        for (args, block_size) in [(["-ab", "."], 1), (["-a", "--apparent-size"], 1024)] {
+            if let Ok(output) = Command::new("du").args(args).current_dir(&root).output() {
+                let files = |du: &str| {
+                    let mut files: Vec<String> = du.lines()
+                        .filter(|line| root.join(line.split('\t').nth(1).unwrap()).is_file())
+                        .map(String::from)
+                        .collect();
+                    files.sort();
+                    files
+                };
+                let actual = String::from_utf8(output.stdout).unwrap();
+                assert_eq!(files(&actual), files(&file_system.to_du(block_size)), "du {:?}", args);
+            }
+        }
+
+        // Existing files are never overwritten
+        assert!(file_system.write_skeleton(&root).is_err());
+        fs::remove_dir_all(&root).unwrap();
+
+        let escaping = FileSystem::from_transcript(&["$ ls", "dir ..", "$ cd /"]);
+        assert_eq!(escaping.write_skeleton(&root).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_can_write_transcript() {
+        let file_system = example_file_system();
+        let input: Vec<String> = lines(example(7).as_bytes()).collect();
+        let depth_first = file_system.to_transcript(Traversal::DepthFirst);
+        // Directories are listed before files, but the example mixes them
+        assert_eq!(depth_first[6..16], input[6..16]);
+        let breadth_first = file_system.to_transcript(Traversal::BreadthFirst);
+        assert_eq!(breadth_first[..12], [
+            "$ cd /", "$ ls", "dir a", "dir d", "14848514 b.txt", "8504156 c.dat",
+            "$ cd /", "$ cd a", "$ ls", "dir e", "29116 f", "2557 g",
+        ]);
+        for transcript in [depth_first, breadth_first] {
+            let replayed = FileSystem::from_transcript(&to_vector_of_str(&transcript));
+            assert_eq!(replayed.to_json(), file_system.to_json());
+        }
+    }
+
+    #[test]
+    fn test_can_read_transcript_from_dir() {
+        let root = std::env::temp_dir().join(format!("aoc-from-dir-{}", std::process::id()));
+        let file_system = example_file_system();
+        file_system.write_skeleton(&root).unwrap();
+
+        let read = FileSystem::from_dir(&root).unwrap();
+        assert_eq!(read.to_json(), file_system.to_json());
+        assert_eq!(read.get_unknown_sizes(), Vec::<String>::new());
+
+        fs::File::create(root.join("a/with space")).unwrap();
+        assert_eq!(FileSystem::from_dir(&root).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_can_replay_transcript_of_dir() {
+        let root = std::env::temp_dir().join(format!("aoc-replay-dir-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("empty")).unwrap();
+        fs::write(root.join("Cargo.toml"), "[package]\n").unwrap();
+        fs::write(root.join("src/lib.rs"), "pub mod days;\n").unwrap();
+        fs::write(root.join("src/days/day01.rs"), "fn main() {}\n").unwrap();
+        // Links are skipped, or the linked directory would be counted twice
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(root.join("src"), root.join("src-link")).unwrap();
+            std::os::unix::fs::symlink(root.join("Cargo.toml"), root.join("src/days/link.toml")).unwrap();
+        }
+
+        // Replaying the transcript of the directory reproduces its size on disk
+        for traversal in [Traversal::DepthFirst, Traversal::BreadthFirst] {
+            let transcript = FileSystem::from_dir(&root).unwrap().to_transcript(traversal);
+            let sizes = get_sizes(&to_vector_of_str(&transcript));
+            assert_eq!(sizes.len(), 4);
+            assert_eq!(sizes.get("/"), Some(&measure(&root)));
+            assert_eq!(sizes.get("/"), Some(&(10 + 14 + 13)));
+            assert_eq!(sizes.get("/src/days"), Some(&measure(&root.join("src/days"))));
+            assert_eq!(sizes.get("/empty"), Some(&0));
+        }
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_get_size_with_example() {
+        let input: Vec<String> = lines(example(7).as_bytes()).collect();
+        let vec = to_vector_of_str(&input);
+        let sizes = get_sizes(&vec);
+        assert_eq!(sizes.len(), 4);
+        assert_eq!(sizes.get("/"), Some(&48381165));
+        assert_eq!(sizes.get("/a"), Some(&94853));
+        assert_eq!(sizes.get("/a/e"), Some(&584));
+        assert_eq!(sizes.get("/d"), Some(&24933642));
+    }
+
+    #[test]
+    fn run_part_1_with_puzzle_input() {
+        let input = read_input();
+        let vec = to_vector_of_str(&input);
+        let sizes = get_sizes(&vec);
+        let sum_of_sizes: i64 = sizes.iter()
+            .filter(|i| *i.1 <= 100_000)
+            .map(|i| *i.1)
+            .sum();
+        assert_eq!(sum_of_sizes, 1350966);
+    }
+
+    #[test]
+    fn run_part_2_with_puzzle_input() {
+        let total_space = 70_000_000;
+        let required_space = 30_000_000;
+
+        let input = read_input();
+        let vec = to_vector_of_str(&input);
+        let sizes = get_sizes(&vec);
+        let used_space = sizes.get("/").expect("root has a size");
+        let free_space = total_space - used_space;
+        let missing_space = required_space - free_space;
+        assert!(missing_space > 0);
+
+        // Sort the list of directory sizes in increasing order
+        let mut list: Vec<i64> = sizes.iter()
+            .map(|i| *i.1)
+            .collect();
+        list.sort();
+
+        // Find the smallest directory that is still bigger than the missing space
+        let size_of_fittest_dir = list.iter()
+            .find(|size| **size > missing_space)
+            .expect("one directory is big enough");
+        assert_eq!(*size_of_fittest_dir, 6296435);
+    }
+
+    /// Generates a file system from 'seed', with 'files' files, and returns its transcript
+    /// together with the directory sizes calculated directly from the file system.
+    fn replay_file_system(seed: u64, files: usize) -> (Vec<String>, HashMap<String, i64>) {
+        let mut random = Random::new(seed);
+        let dirs = generate_file_system(&mut random, files);
+        let transcript = write_transcript(&mut random, &dirs);
+        let sizes = get_total_sizes(&dirs).into_iter()
+            .enumerate()
+            .map(|(i, size)| (get_dir_path(&dirs, i), size))
+            .collect();
+        (transcript, sizes)
+    }
+
+    fn random_file_system(random: &mut Random, size: usize) -> (u64, usize) {
+        (random.next_u64(), size)
+    }
+
+    fn shrink_file_system((seed, files): &(u64, usize)) -> Vec<(u64, usize)> {
+        shrink_usize(files).into_iter().map(|files| (*seed, files)).collect()
+    }
+
+    #[test]
+    fn property_sizes_agree_with_file_system() {
+        check(random_file_system, shrink_file_system, |(seed, files)| {
+            let (transcript, expected) = replay_file_system(*seed, *files);
+            get_sizes(&to_vector_of_str(&transcript)) == expected
+        });
+    }
+
+    #[test]
+    fn property_parent_is_at_least_sum_of_children() {
+        check(random_file_system, shrink_file_system, |(seed, files)| {
+            let (transcript, _) = replay_file_system(*seed, *files);
+            let sizes = get_sizes(&to_vector_of_str(&transcript));
+            let mut sum_of_children: HashMap<&str, i64> = HashMap::new();
+            for (path, size) in &sizes {
+                if path != "/" {
+                    let parent = match path.rfind('/') {
+                        Some(0) => "/",
+                        Some(i) => &path[..i],
+                        None => return false,
+                    };
+                    *sum_of_children.entry(parent).or_insert(0) += size;
+                }
+            }
+            sum_of_children.iter().all(|(parent, sum)| sizes.get(*parent).is_some_and(|size| size >= sum))
+        });
+    }
+}
@@ -0,0 +1,860 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use crate::{lines, to_vector_of_str};
+use crate::input::puzzle;
+use crate::solution::Solution;
+use crate::geom::Point;
+use crate::num::gcd;
+
+const ZERO_AS_U8: u8 = b'0';
+
+pub fn read_input() -> Vec<String> {
+    lines(puzzle(8).as_bytes()).collect()
+}
+
+pub fn to_matrix_of_u8(rows: &[&str]) -> Vec<Vec<u8>> {
+    rows.iter()
+        .map(|row| to_vector_of_u8(row))
+        .collect()
+}
+
+fn to_vector_of_u8(row: &str) -> Vec<u8> {
+    row.chars()
+        .map(|c| c as u8 - ZERO_AS_U8)
+        .collect()
+}
+
+/// Returns the height of the tree at 'pos', which is assumed to inside the forest.
+pub fn get_height(forest: &[Vec<u8>], pos: &Point) -> u8 {
+    *forest
+        .get(pos.y as usize)
+        .expect("matrix has enough rows")
+        .get(pos.x as usize)
+        .expect("matrix has enough columns")
+}
+
+pub fn find_visible_trees(forest: &[Vec<u8>], start: Point, dx: i32, dy: i32) -> Vec<Point> {
+    let mut visible_trees = Vec::new();
+
+    let mut height_of_highest_so_far = -1;
+    let mut pos = start;
+    while is_in_forest(forest, &pos) {
+        let height = get_height(forest, &pos) as i32;
+        if height > height_of_highest_so_far {
+            visible_trees.push(pos);
+            height_of_highest_so_far = height;
+        }
+        pos = pos.translate(dx, dy);
+    }
+
+    visible_trees
+}
+
+/// Returns true if the given position is inside the forest.
+fn is_in_forest(forest: &[Vec<u8>], pos: &Point) -> bool {
+    forest.len() > pos.y as usize &&
+        forest.first().map_or(0, Vec::len) > pos.x as usize
+}
+
+pub fn find_all_visible_trees(forest: &[Vec<u8>]) -> HashSet<Point> {
+    let mut visible_trees: HashSet<Point> = HashSet::new();
+
+    let width = forest.first().map_or(0, Vec::len);
+    let height = forest.len();
+
+    // Top
+    for x in 0..width {
+        let start = Point::of(x as i32, 0);
+        let vec = find_visible_trees(forest, start, 0, 1);
+        for p in vec { visible_trees.insert(p); }
+    }
+    // Bottom
+    for x in 0..width {
+        let start = Point::of(x as i32, (height - 1) as i32);
+        let vec = find_visible_trees(forest, start, 0, -1);
+        for p in vec { visible_trees.insert(p); }
+    }
+    // Left
+    for y in 0..height {
+        let start = Point::of(0, y as i32);
+        let vec = find_visible_trees(forest, start, 1, 0);
+        for p in vec { visible_trees.insert(p); }
+    }
+    // Right
+    for y in 0..height {
+        let start = Point::of((width - 1) as i32, y as i32);
+        let vec = find_visible_trees(forest, start, -1, 0);
+        for p in vec { visible_trees.insert(p); }
+    }
+
+    visible_trees
+}
+
+/// Returns the four viewing distances for position 'start'.
+pub fn get_viewing_distances(forest: &[Vec<u8>], start: Point) -> Vec<i32> {
+    vec![
+        get_viewing_distance(forest, &start, 1, 0),
+        get_viewing_distance(forest, &start, -1, 0),
+        get_viewing_distance(forest, &start, 0, 1),
+        get_viewing_distance(forest, &start, 0, -1),
+    ]
+}
+
+/// Returns the viewing distance for the direction defined by dx and dy.
+pub fn get_viewing_distance(forest: &[Vec<u8>], start: &Point, dx: i32, dy: i32) -> i32 {
+    ViewModel::default().get_viewing_distance(forest, start, (dx, dy))
+}
+
+/// Calculates the scenic score by multiplying the different viewing distances.
+pub fn calculate_scenic_score(viewing_distances: &[i32]) -> i32 {
+    viewing_distances.iter().product()
+}
+
+/// Calculates the scenic score like 'calculate_scenic_score', but returns an error if
+/// the product overflows, as it can with many directions in a huge forest.
+pub fn checked_calculate_scenic_score(viewing_distances: &[i32]) -> Result<i32, String> {
+    viewing_distances.iter()
+        .try_fold(1i32, |score, distance| score.checked_mul(*distance))
+        .ok_or_else(|| format!("Scenic score of {:?} overflows i32", viewing_distances))
+}
+
+/// Returns the highest scenic score of any tree in the forest, or 0 if it is empty.
+pub fn find_max_scenic_score(forest: &[Vec<u8>]) -> i32 {
+    let width = forest.first().map_or(0, Vec::len);
+    let height = forest.len();
+
+    (0..width)
+        .flat_map(|x| (0..height).map(move |y| Point::of(x as i32, y as i32)))
+        .map(|start| calculate_scenic_score(&get_viewing_distances(forest, start)))
+        .max()
+        .unwrap_or(0)
+}
+
+/// The four horizontal and vertical directions, in the order of 'get_viewing_distances'.
+pub const CARDINAL: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+/// The four diagonal directions.
+pub const DIAGONAL: [(i32, i32); 4] = [(1, 1), (-1, 1), (1, -1), (-1, -1)];
+
+/// Decides which trees block the view of a tree.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Blocking {
+    /// Trees at least as tall as the viewer block the view.
+    AtLeastAsTall,
+    /// Only trees taller than the viewer block the view.
+    Taller,
+}
+
+/// Decides which trees can be seen from a tree: the directions to look in, which trees
+/// block the view, and how high above the top of its tree the viewer's eye is. The default
+/// looks in the four cardinal directions, and is blocked by trees at least as tall.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ViewModel {
+    directions: Vec<(i32, i32)>,
+    blocking: Blocking,
+    eye_height: i32,
+}
+
+impl Default for ViewModel {
+    fn default() -> Self {
+        ViewModel::of(&CARDINAL, Blocking::AtLeastAsTall, 0)
+    }
+}
+
+impl ViewModel {
+    /// Creates a new ViewModel. Each direction is a slope (dx, dy), which is reduced to the
+    /// smallest step that only visits whole trees, e.g. (2, 4) looks at every tree on (1, 2).
+    pub fn of(directions: &[(i32, i32)], blocking: Blocking, eye_height: i32) -> Self {
+        let directions = directions.iter()
+            .map(|(dx, dy)| {
+                let divisor = gcd(*dx, *dy);
+                assert!(divisor != 0, "Direction is not (0, 0)");
+                (dx / divisor, dy / divisor)
+            })
+            .collect();
+        ViewModel { directions, blocking, eye_height }
+    }
+
+    /// Creates a new ViewModel that looks in the cardinal and the diagonal directions.
+    pub fn eight_way(blocking: Blocking, eye_height: i32) -> Self {
+        ViewModel::of(&[CARDINAL, DIAGONAL].concat(), blocking, eye_height)
+    }
+
+    /// Returns the reduced directions to look in.
+    pub fn directions(&self) -> &[(i32, i32)] {
+        &self.directions
+    }
+
+    /// Returns which trees block the view.
+    pub fn blocking(&self) -> Blocking {
+        self.blocking
+    }
+
+    /// Returns how high above the top of its tree the viewer's eye is.
+    pub fn eye_height(&self) -> i32 {
+        self.eye_height
+    }
+
+    /// Returns true if a tree of height 'other' blocks the view from a tree of height 'height'.
+    pub fn blocks(&self, height: u8, other: u8) -> bool {
+        let eye = height as i32 + self.eye_height;
+        match self.blocking {
+            Blocking::AtLeastAsTall => other as i32 >= eye,
+            Blocking::Taller => other as i32 > eye,
+        }
+    }
+
+    /// Returns the viewing distance from 'start' in 'direction', and whether the view
+    /// reaches the edge of the forest without being blocked. Panics if 'direction' is (0, 0),
+    /// as the view would never leave 'start'.
+    fn look(&self, forest: &[Vec<u8>], start: &Point, direction: (i32, i32)) -> (i32, bool) {
+        assert!(direction != (0, 0), "Direction is not (0, 0)");
+        let my_height = get_height(forest, start);
+
+        let mut count = 0;
+        let mut pos = start.translate(direction.0, direction.1);
+        while is_in_forest(forest, &pos) {
+            count += 1;
+            if self.blocks(my_height, get_height(forest, &pos)) {
+                return (count, false);
+            }
+            pos = pos.translate(direction.0, direction.1);
+        }
+        (count, true)
+    }
+
+    /// Returns the number of trees that can be seen from 'start' in 'direction'.
+    pub fn get_viewing_distance(&self, forest: &[Vec<u8>], start: &Point, direction: (i32, i32)) -> i32 {
+        self.look(forest, start, direction).0
+    }
+
+    /// Returns the viewing distances for position 'start', one for each direction.
+    pub fn get_viewing_distances(&self, forest: &[Vec<u8>], start: Point) -> Vec<i32> {
+        self.directions.iter()
+            .map(|direction| self.get_viewing_distance(forest, &start, *direction))
+            .collect()
+    }
+
+    /// Returns the scenic score of the tree at 'start', the product of its viewing distances.
+    pub fn get_scenic_score(&self, forest: &[Vec<u8>], start: Point) -> i32 {
+        calculate_scenic_score(&self.get_viewing_distances(forest, start))
+    }
+
+    /// Returns the highest scenic score of any tree in the forest, or 0 if it is empty.
+    pub fn find_max_scenic_score(&self, forest: &[Vec<u8>]) -> i32 {
+        map_forest(forest, |pos| self.get_scenic_score(forest, pos))
+            .into_iter()
+            .flatten()
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns true if the tree at 'pos' can be seen from outside the forest, because
+    /// its view in at least one direction reaches the edge.
+    pub fn is_visible(&self, forest: &[Vec<u8>], pos: &Point) -> bool {
+        self.directions.iter().any(|direction| self.look(forest, pos, *direction).1)
+    }
+
+    /// Returns all trees that can be seen from outside the forest.
+    pub fn find_all_visible_trees(&self, forest: &[Vec<u8>]) -> HashSet<Point> {
+        map_forest(forest, |pos| pos)
+            .into_iter()
+            .flatten()
+            .filter(|pos| self.is_visible(forest, pos))
+            .collect()
+    }
+}
+
+/// The cells of the forest, used as a terrain heightmap, that an observer can see.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Viewshed {
+    pub mask: Vec<Vec<bool>>,
+    pub visible: usize,
+    pub hidden: usize,
+}
+
+/// Returns the viewshed of an observer standing on top of the tree at 'observer', with
+/// their eye 'eye_height' above it. A cell is visible if the ray from the eye to the top
+/// of the cell is not below the top of any cell on the Bresenham line between them.
+/// Slopes are measured in steps along the major axis of the line, to compare them exactly.
+pub fn compute_viewshed(forest: &[Vec<u8>], observer: Point, eye_height: i32) -> Viewshed {
+    let eye = get_height(forest, &observer) as i64 + eye_height as i64;
+    let is_visible = |target: Point| {
+        let line = observer.line_to(&target);
+        let steps = line.len() as i64 - 1;
+        let rise = get_height(forest, &target) as i64 - eye;
+        line.iter()
+            .enumerate()
+            .take(line.len() - 1)
+            .skip(1)
+            .all(|(step, pos)| (get_height(forest, pos) as i64 - eye) * steps <= rise * step as i64)
+    };
+
+    let mask = map_forest(forest, is_visible);
+    let visible = mask.iter().flatten().filter(|visible| **visible).count();
+    let hidden = mask.iter().map(Vec::len).sum::<usize>() - visible;
+    Viewshed { mask, visible, hidden }
+}
+
+/// An edge of the forest, from which the trees are looked at.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Edge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+pub const EDGES: [Edge; 4] = [Edge::Top, Edge::Bottom, Edge::Left, Edge::Right];
+
+impl Edge {
+    /// Returns the lowercase name of this edge, e.g. "top".
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Edge::Top => "top",
+            Edge::Bottom => "bottom",
+            Edge::Left => "left",
+            Edge::Right => "right",
+        }
+    }
+
+    /// Returns the step (dx, dy) of a line of sight from this edge into the forest.
+    pub const fn step(&self) -> (i32, i32) {
+        match self {
+            Edge::Top => (0, 1),
+            Edge::Bottom => (0, -1),
+            Edge::Left => (1, 0),
+            Edge::Right => (-1, 0),
+        }
+    }
+
+    /// Returns the first tree of each line of sight from this edge into a forest of the given size.
+    pub fn get_starts(&self, width: usize, height: usize) -> Vec<Point> {
+        match self {
+            Edge::Top => (0..width).map(|x| Point::of(x as i32, 0)).collect(),
+            Edge::Bottom => (0..width).map(|x| Point::of(x as i32, height as i32 - 1)).collect(),
+            Edge::Left => (0..height).map(|y| Point::of(0, y as i32)).collect(),
+            Edge::Right => (0..height).map(|y| Point::of(width as i32 - 1, y as i32)).collect(),
+        }
+    }
+}
+
+/// Returns a matrix the size of the forest, with the result of 'f' for each tree.
+fn map_forest<T>(forest: &[Vec<u8>], f: impl Fn(Point) -> T) -> Vec<Vec<T>> {
+    (0..forest.len())
+        .map(|y| (0..forest[y].len()).map(|x| f(Point::of(x as i32, y as i32))).collect())
+        .collect()
+}
+
+/// Returns the scenic score of every tree in the forest.
+pub fn get_scenic_scores(forest: &[Vec<u8>]) -> Vec<Vec<i32>> {
+    map_forest(forest, |pos| calculate_scenic_score(&get_viewing_distances(forest, pos)))
+}
+
+/// Returns for every tree in the forest whether it is visible from 'edge'.
+pub fn get_visibility_mask(forest: &[Vec<u8>], edge: Edge) -> Vec<Vec<bool>> {
+    let mut mask = map_forest(forest, |_| false);
+    let (dx, dy) = edge.step();
+    for start in edge.get_starts(forest.first().map_or(0, Vec::len), forest.len()) {
+        for pos in find_visible_trees(forest, start, dx, dy) {
+            mask[pos.y as usize][pos.x as usize] = true;
+        }
+    }
+    mask
+}
+
+/// Returns for every tree in the forest the height of the highest tree between 'edge'
+/// and that tree, including the tree itself. A tree is visible from 'edge' if it is
+/// higher than the horizon of the tree before it.
+pub fn get_horizon_profile(forest: &[Vec<u8>], edge: Edge) -> Vec<Vec<u8>> {
+    let mut horizon = map_forest(forest, |_| 0);
+    let (dx, dy) = edge.step();
+    for start in edge.get_starts(forest.first().map_or(0, Vec::len), forest.len()) {
+        let mut highest = 0;
+        let mut pos = start;
+        while is_in_forest(forest, &pos) {
+            highest = highest.max(get_height(forest, &pos));
+            horizon[pos.y as usize][pos.x as usize] = highest;
+            pos = pos.translate(dx, dy);
+        }
+    }
+    horizon
+}
+
+/// A tree with its viewing distances, in the order of 'get_viewing_distances', and scenic score.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ScenicSpot {
+    pub pos: Point,
+    pub distances: Vec<i32>,
+    pub score: i32,
+}
+
+/// Returns the 'n' trees with the highest scenic scores, the highest first. Trees with
+/// the same score are ordered by row, and then by column.
+pub fn rank_scenic_spots(forest: &[Vec<u8>], n: usize) -> Vec<ScenicSpot> {
+    let mut spots: Vec<ScenicSpot> = map_forest(forest, |pos| {
+        let distances = get_viewing_distances(forest, pos);
+        let score = calculate_scenic_score(&distances);
+        ScenicSpot { pos, distances, score }
+    }).into_iter().flatten().collect();
+    spots.sort_by_key(|spot| (-spot.score, spot.pos.y, spot.pos.x));
+    spots.truncate(n);
+    spots
+}
+
+/// Returns the map named 'name' of the forest, or None if there is no such map. The maps
+/// are "heights", "scenic", "visible", and "visible-EDGE" and "horizon-EDGE" for each edge.
+pub fn get_map(forest: &[Vec<u8>], name: &str) -> Option<Vec<Vec<i64>>> {
+    fn to_i64<T: Into<i64>>(matrix: Vec<Vec<T>>) -> Vec<Vec<i64>> {
+        matrix.into_iter().map(|row| row.into_iter().map(T::into).collect()).collect()
+    }
+    let edge = |prefix: &str| name.strip_prefix(prefix)
+        .and_then(|edge| EDGES.iter().find(|e| e.name() == edge).copied());
+
+    if let Some(edge) = edge("visible-") {
+        return Some(to_i64(get_visibility_mask(forest, edge)));
+    }
+    if let Some(edge) = edge("horizon-") {
+        return Some(to_i64(get_horizon_profile(forest, edge)));
+    }
+    match name {
+        "heights" => Some(to_i64(forest.to_vec())),
+        "scenic" => Some(to_i64(get_scenic_scores(forest))),
+        "visible" => {
+            let visible = find_all_visible_trees(forest);
+            Some(map_forest(forest, |pos| i64::from(visible.contains(&pos))))
+        }
+        _ => None,
+    }
+}
+
+/// Formats 'matrix' as CSV, with one line per row.
+pub fn to_csv<T: Display>(matrix: &[Vec<T>]) -> String {
+    matrix.iter()
+        .map(|row| row.iter().map(T::to_string).collect::<Vec<String>>().join(","))
+        .map(|line| line + "\n")
+        .collect()
+}
+
+/// Formats the ranked scenic spots as CSV, with a header line.
+pub fn spots_to_csv(spots: &[ScenicSpot]) -> String {
+    let mut csv = String::from("x,y,score,right,left,down,up\n");
+    for spot in spots {
+        let distances: Vec<String> = spot.distances.iter().map(i32::to_string).collect();
+        csv += &format!("{},{},{},{}\n", spot.pos.x, spot.pos.y, spot.score, distances.join(","));
+    }
+    csv
+}
+
+/// The highest grey value a PGM image can have.
+const PGM_MAX_VALUE: i64 = 65_535;
+
+/// Formats 'matrix' as a plain PGM image, where the highest value is white. Negative
+/// values are black, and values are scaled down if they exceed what PGM can represent.
+pub fn to_pgm(matrix: &[Vec<i64>]) -> String {
+    let width = matrix.first().map_or(0, Vec::len);
+    let max = matrix.iter().flatten().copied().max().unwrap_or(0).max(1);
+    let max_value = max.min(PGM_MAX_VALUE);
+
+    let mut pgm = format!("P2\n{} {}\n{}\n", width, matrix.len(), max_value);
+    for row in matrix {
+        let values: Vec<String> = row.iter()
+            // Widened, because the product overflows for values close to i64::MAX
+            .map(|value| (*value.max(&0) as i128 * max_value as i128 / max as i128).to_string())
+            .collect();
+        pgm += &values.join(" ");
+        pgm += "\n";
+    }
+    pgm
+}
+
+#[derive(Default)]
+pub struct Day08 {
+    forest: Vec<Vec<u8>>,
+}
+
+impl Solution for Day08 {
+    fn parse(&mut self, input: &str) {
+        let rows: Vec<String> = lines(input.as_bytes()).collect();
+        self.forest = to_matrix_of_u8(&to_vector_of_str(&rows));
+    }
+
+    fn part_1(&self) -> String {
+        find_all_visible_trees(&self.forest).len().to_string()
+    }
+
+    fn part_2(&self) -> String {
+        find_max_scenic_score(&self.forest).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::max;
+    use std::collections::HashSet;
+    use crate::input::example;
+    use super::*;
+
+    #[test]
+    fn test_can_read_input() {
+        let input = read_input();
+        assert_eq!(input[0], "102212110110302230012132130441442043243242145112422525112333344240121120342411110001231112222021211");
+    }
+
+    #[test]
+    fn test_can_convert_to_matrix_of_int() {
+        let input = vec!["123", "456", "789"];
+        let result = to_matrix_of_u8(&input);
+        assert_eq!(result, vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]])
+    }
+
+    #[test]
+    fn test_can_get_height() {
+        let forest = to_matrix_of_u8(&["123", "456", "789"]);
+        assert_eq!(get_height(&forest, &Point::of(0, 0)), 1);
+        assert_eq!(get_height(&forest, &Point::of(1, 0)), 2);
+        assert_eq!(get_height(&forest, &Point::of(2, 0)), 3);
+        assert_eq!(get_height(&forest, &Point::of(0, 2)), 7);
+        assert_eq!(get_height(&forest, &Point::of(1, 2)), 8);
+        assert_eq!(get_height(&forest, &Point::of(2, 2)), 9);
+    }
+
+    #[test]
+    fn test_is_in_forest() {
+        let forest = to_matrix_of_u8(&["123", "456", "789"]);
+
+        assert!(is_in_forest(&forest, &Point::of(0, 0)));
+        assert!(is_in_forest(&forest, &Point::of(1, 2)));
+        assert!(is_in_forest(&forest, &Point::of(2, 0)));
+
+        assert!(!is_in_forest(&forest, &Point::of(3, 0)));
+        assert!(!is_in_forest(&forest, &Point::of(2, 3)));
+    }
+
+    #[test]
+    fn test_can_find_visible_trees() {
+        let forest = to_matrix_of_u8(&[
+            "123",
+            "645",
+            "709",
+        ]);
+
+        assert_eq!(find_visible_trees(&forest, Point::of(0, 0), 1, 0),
+                   vec![Point::of(0, 0), Point::of(1, 0), Point::of(2, 0)]);
+        assert_eq!(find_visible_trees(&forest, Point::of(2, 0), -1, 0),
+                   vec![Point::of(2, 0)]);
+        assert_eq!(find_visible_trees(&forest, Point::of(1, 0), 0, 1),
+                   vec![Point::of(1, 0), Point::of(1, 1)]);
+        assert_eq!(find_visible_trees(&forest, Point::of(1, 2), 0, -1),
+                   vec![Point::of(1, 2), Point::of(1, 1)]);
+    }
+
+    #[test]
+    fn test_can_find_all_visible_trees_1() {
+        let forest = to_matrix_of_u8(&[
+            "123",
+            "645",
+            "709",
+        ]);
+
+        let expected: HashSet<Point> = HashSet::from([
+            Point::of(0, 0),
+            Point::of(0, 1),
+            Point::of(0, 2),
+            Point::of(1, 0),
+            Point::of(1, 1),
+            Point::of(1, 2),
+            Point::of(2, 0),
+            Point::of(2, 1),
+            Point::of(2, 2),
+        ]);
+
+        assert_eq!(find_all_visible_trees(&forest), expected);
+    }
+
+    #[test]
+    fn test_can_find_all_visible_trees_2() {
+        let forest = to_matrix_of_u8(&[
+            "123",
+            "605",
+            "709",
+        ]);
+
+        let expected: HashSet<Point> = HashSet::from([
+            Point::of(0, 0),
+            Point::of(0, 1),
+            Point::of(0, 2),
+            Point::of(1, 0),
+            //Point::of(1, 1),
+            Point::of(1, 2),
+            Point::of(2, 0),
+            Point::of(2, 1),
+            Point::of(2, 2),
+        ]);
+
+        assert_eq!(find_all_visible_trees(&forest), expected);
+    }
+
+    #[test]
+    fn test_with_example_part_1() {
+        let input: Vec<String> = lines(example(8).as_bytes()).collect();
+        let forest = to_matrix_of_u8(&to_vector_of_str(&input));
+
+        let expected: HashSet<Point> = HashSet::from([
+            Point::of(0, 0),
+            Point::of(1, 0),
+            Point::of(2, 0),
+            Point::of(3, 0),
+            Point::of(4, 0),
+            Point::of(0, 1),
+            Point::of(1, 1),
+            Point::of(2, 1),
+            //Point::of(3, 1),
+            Point::of(4, 1),
+            Point::of(0, 2),
+            Point::of(1, 2),
+            //Point::of(2, 2),
+            Point::of(3, 2),
+            Point::of(4, 2),
+            Point::of(0, 3),
+            //Point::of(1, 3),
+            Point::of(2, 3),
+            //Point::of(3, 3),
+            Point::of(4, 3),
+            Point::of(0, 4),
+            Point::of(1, 4),
+            Point::of(2, 4),
+            Point::of(3, 4),
+            Point::of(4, 4),
+        ]);
+
+        assert_eq!(find_all_visible_trees(&forest), expected);
+        assert_eq!(find_all_visible_trees(&forest).len(), 21);
+    }
+
+    #[test]
+    fn test_calculate_scenic_score() {
+        assert_eq!(calculate_scenic_score(&[1, 1, 2, 2]), 4);
+        assert_eq!(calculate_scenic_score(&[2, 2, 1, 2]), 8);
+        assert_eq!(calculate_scenic_score(&[-1, 5, 7]), -35);
+    }
+
+    #[test]
+    fn test_checked_calculate_scenic_score() {
+        assert_eq!(checked_calculate_scenic_score(&[1, 1, 2, 2]), Ok(4));
+        assert_eq!(checked_calculate_scenic_score(&[]), Ok(1));
+        assert_eq!(checked_calculate_scenic_score(&[0, 100000, 100000]), Ok(0));
+        assert_eq!(
+            checked_calculate_scenic_score(&[1000, 1000, 1000, 1000]),
+            Err(String::from("Scenic score of [1000, 1000, 1000, 1000] overflows i32")),
+        );
+    }
+
+    #[test]
+    fn test_with_example_part_2() {
+        let input: Vec<String> = lines(example(8).as_bytes()).collect();
+        let forest = to_matrix_of_u8(&to_vector_of_str(&input));
+
+        let viewing_distances = get_viewing_distances(&forest, Point::of(2, 1));
+        assert_eq!(calculate_scenic_score(&viewing_distances), 4);
+        let viewing_distances = get_viewing_distances(&forest, Point::of(2, 3));
+        assert_eq!(calculate_scenic_score(&viewing_distances), 8);
+        let viewing_distances = get_viewing_distances(&forest, Point::of(4, 3));
+        assert_eq!(calculate_scenic_score(&viewing_distances), 0);
+    }
+
+    fn example_forest() -> Vec<Vec<u8>> {
+        let input: Vec<String> = lines(example(8).as_bytes()).collect();
+        to_matrix_of_u8(&to_vector_of_str(&input))
+    }
+
+    #[test]
+    fn test_can_get_scenic_scores() {
+        let scores = get_scenic_scores(&example_forest());
+        assert_eq!(scores[1][2], 4);
+        assert_eq!(scores[3][2], 8);
+        assert_eq!(scores[3][4], 0);
+        assert_eq!(*scores.iter().flatten().max().unwrap(), find_max_scenic_score(&example_forest()));
+    }
+
+    #[test]
+    fn test_can_rank_scenic_spots() {
+        let spots = rank_scenic_spots(&example_forest(), 2);
+        assert_eq!(spots, vec![
+            ScenicSpot { pos: Point::of(2, 3), distances: vec![2, 2, 1, 2], score: 8 },
+            ScenicSpot { pos: Point::of(1, 2), distances: vec![3, 1, 2, 1], score: 6 },
+        ]);
+        assert_eq!(spots_to_csv(&spots), "x,y,score,right,left,down,up\n2,3,8,2,2,1,2\n1,2,6,3,1,2,1\n");
+    }
+
+    #[test]
+    fn test_can_get_visibility_mask() {
+        let forest = example_forest();
+        let mask = get_visibility_mask(&forest, Edge::Left);
+        assert_eq!(mask[1], vec![true, true, false, false, false]);
+
+        // A tree is visible if it is visible from any edge
+        let visible = find_all_visible_trees(&forest);
+        let masks: Vec<Vec<Vec<bool>>> = EDGES.iter().map(|edge| get_visibility_mask(&forest, *edge)).collect();
+        for (y, row) in forest.iter().enumerate() {
+            for x in 0..row.len() {
+                let any = masks.iter().any(|mask| mask[y][x]);
+                assert_eq!(any, visible.contains(&Point::of(x as i32, y as i32)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_can_get_horizon_profile() {
+        let forest = example_forest();
+        assert_eq!(get_horizon_profile(&forest, Edge::Left)[0], vec![3, 3, 3, 7, 7]);
+        assert_eq!(get_horizon_profile(&forest, Edge::Right)[0], vec![7, 7, 7, 7, 3]);
+        let top = get_horizon_profile(&forest, Edge::Top);
+        assert_eq!(top.iter().map(|row| row[0]).collect::<Vec<u8>>(), vec![3, 3, 6, 6, 6]);
+    }
+
+    #[test]
+    fn test_can_get_map() {
+        let forest = example_forest();
+        assert_eq!(get_map(&forest, "heights").unwrap()[0], vec![3, 0, 3, 7, 3]);
+        assert_eq!(get_map(&forest, "visible").unwrap()[1], vec![1, 1, 1, 0, 1]);
+        assert_eq!(get_map(&forest, "visible-top").unwrap()[1], vec![0, 1, 1, 0, 0]);
+        assert_eq!(get_map(&forest, "horizon-bottom").unwrap()[4], vec![3, 5, 3, 9, 0]);
+        assert_eq!(get_map(&forest, "horizon-middle"), None);
+    }
+
+    #[test]
+    fn test_can_export_matrix() {
+        let matrix: Vec<Vec<i64>> = vec![vec![0, 2], vec![4, -1]];
+        assert_eq!(to_csv(&matrix), "0,2\n4,-1\n");
+        assert_eq!(to_pgm(&matrix), "P2\n2 2\n4\n0 2\n4 0\n");
+        assert_eq!(to_pgm(&[vec![0, 131_070]]), "P2\n2 1\n65535\n0 65535\n");
+        assert_eq!(to_pgm(&[vec![i64::MAX / 2, i64::MAX]]), "P2\n2 1\n65535\n32767 65535\n");
+        assert_eq!(to_pgm(&Vec::new()), "P2\n0 0\n1\n");
+    }
+
+    #[test]
+    fn test_can_analyse_empty_forest() {
+        let forest: Vec<Vec<u8>> = Vec::new();
+        assert_eq!(find_max_scenic_score(&forest), 0);
+        assert_eq!(ViewModel::default().find_max_scenic_score(&forest), 0);
+        assert!(get_visibility_mask(&forest, Edge::Left).is_empty());
+        assert!(get_horizon_profile(&forest, Edge::Top).is_empty());
+        assert!(find_all_visible_trees(&forest).is_empty());
+        assert_eq!(get_map(&forest, "visible"), Some(Vec::new()));
+
+        let mut solution = Day08::default();
+        solution.parse("");
+        assert_eq!(solution.part_1(), "0");
+        assert_eq!(solution.part_2(), "0");
+    }
+
+    #[test]
+    fn test_default_view_model_is_unchanged() {
+        let model = ViewModel::default();
+        for forest in [example_forest(), to_matrix_of_u8(&to_vector_of_str(&read_input()))] {
+            assert_eq!(model.find_all_visible_trees(&forest), find_all_visible_trees(&forest));
+            assert_eq!(model.find_max_scenic_score(&forest), find_max_scenic_score(&forest));
+        }
+        assert_eq!(model.get_viewing_distances(&example_forest(), Point::of(2, 3)), vec![2, 2, 1, 2]);
+    }
+
+    #[test]
+    fn test_view_model_with_diagonals() {
+        let forest = example_forest();
+        let model = ViewModel::eight_way(Blocking::AtLeastAsTall, 0);
+        // The tree of height 5 at (2, 3) looks up-right past the 3 and the 2 to the edge
+        assert_eq!(model.get_viewing_distances(&forest, Point::of(2, 3)), vec![2, 2, 1, 2, 1, 1, 2, 1]);
+        // The tree of height 4 at (3, 3) is hidden in the cardinal directions, but not diagonally
+        assert!(!ViewModel::default().is_visible(&forest, &Point::of(3, 3)));
+        assert!(model.is_visible(&forest, &Point::of(3, 3)));
+        assert!(!model.is_visible(&forest, &Point::of(2, 2)));
+    }
+
+    #[test]
+    fn test_view_model_with_slopes() {
+        let forest = to_matrix_of_u8(&["1000", "0000", "0900", "0005"]);
+        let model = ViewModel::of(&[(4, 2)], Blocking::AtLeastAsTall, 0);
+        assert_eq!(model.directions(), [(2, 1)]);
+        assert_eq!(model.get_viewing_distance(&forest, &Point::of(0, 0), (2, 1)), 1);
+        assert!(model.is_visible(&forest, &Point::of(0, 0)));
+        assert_eq!(model.get_viewing_distance(&forest, &Point::of(1, 2), (1, 1)), 1);
+    }
+
+    #[test]
+    fn test_view_model_blocking_and_eye_height() {
+        let forest = to_matrix_of_u8(&["355363"]);
+        let start = Point::of(1, 0);
+        let at_least_as_tall = ViewModel::of(&[(1, 0)], Blocking::AtLeastAsTall, 0);
+        let taller = ViewModel::of(&[(1, 0)], Blocking::Taller, 0);
+        let raised = ViewModel::of(&[(1, 0)], Blocking::AtLeastAsTall, 2);
+        assert_eq!(at_least_as_tall.get_viewing_distance(&forest, &start, (1, 0)), 1);
+        assert_eq!(taller.get_viewing_distance(&forest, &start, (1, 0)), 3);
+        assert_eq!(raised.get_viewing_distance(&forest, &start, (1, 0)), 4);
+        assert!(raised.is_visible(&forest, &start));
+        assert!(!at_least_as_tall.is_visible(&to_matrix_of_u8(&["555"]), &start));
+        assert!(taller.is_visible(&to_matrix_of_u8(&["555"]), &start));
+    }
+
+    #[test]
+    #[should_panic(expected = "Direction is not (0, 0)")]
+    fn test_view_model_rejects_zero_direction() {
+        let forest = to_matrix_of_u8(&["555"]);
+        ViewModel::of(&[(1, 0)], Blocking::Taller, 0).get_viewing_distance(&forest, &Point::of(1, 0), (0, 0));
+    }
+
+    #[test]
+    fn test_can_compute_viewshed() {
+        let forest = to_matrix_of_u8(&["1215", "0000"]);
+        let viewshed = compute_viewshed(&forest, Point::of(0, 0), 0);
+        // The 1 at (2, 0) is hidden behind the 2, but the 5 rises above the line of sight
+        assert_eq!(viewshed.mask[0], vec![true, true, false, true]);
+        // The line to (3, 1) passes over the 2 at (1, 0)
+        assert_eq!(viewshed.mask[1], vec![true, true, true, false]);
+        assert_eq!((viewshed.visible, viewshed.hidden), (6, 2));
+
+        // From higher up, the view reaches past the 2
+        let viewshed = compute_viewshed(&forest, Point::of(0, 0), 3);
+        assert_eq!(viewshed.mask[0], vec![true, true, true, true]);
+    }
+
+    #[test]
+    fn test_can_compute_viewshed_with_example() {
+        let forest = to_matrix_of_u8(&["55555"; 5]);
+        assert_eq!(compute_viewshed(&forest, Point::of(2, 2), 0).visible, 25);
+
+        // The 3 at the end of the first row is hidden behind the 7
+        let viewshed = compute_viewshed(&example_forest(), Point::of(0, 0), 0);
+        assert_eq!(viewshed.mask[0], vec![true, true, true, true, false]);
+        assert_eq!(viewshed.visible + viewshed.hidden, 25);
+    }
+
+    #[test]
+    fn run_part_1_with_puzzle_input() {
+        let input = read_input();
+        let forest = to_matrix_of_u8(&to_vector_of_str(&input));
+        let visible = find_all_visible_trees(&forest);
+        assert_eq!(visible.len(), 1843);
+    }
+
+    #[test]
+    fn run_part_2_with_puzzle_input() {
+        let input = read_input();
+        let forest = to_matrix_of_u8(&to_vector_of_str(&input));
+
+        let width = forest.first().unwrap().len();
+        let height = forest.len();
+
+        let mut max_score = 0;
+        for x in 0..width {
+            for y in 0..height {
+                let start = Point::of(x as i32, y as i32);
+                let distances = get_viewing_distances(&forest, start);
+                let score = calculate_scenic_score(&distances);
+                max_score = max(score, max_score);
+            }
+        }
+
+        assert_eq!(max_score, 180_000);
+    }
+}
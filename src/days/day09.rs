@@ -0,0 +1,830 @@
+use crate::geom::{sgn, Move, Point, Split};
+use crate::{lines, to_vector_of_str};
+use crate::input::puzzle;
+use crate::solution::Solution;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+const ORIGO: Point = Point::of(0, 0);
+
+pub fn read_input() -> Vec<String> {
+    lines(puzzle(9).as_bytes()).collect()
+}
+
+pub fn parse_moves(moves: &Vec<&str>) -> Vec<Move> {
+    moves.iter().map(|s| Move::from_str(s)).collect()
+}
+
+pub const fn calculate_tail_move(head: &Point, tail: &Point) -> Option<Move> {
+    if tail.touches(head) {
+        return None;
+    }
+    // If the head is on the same row or column as the tail,
+    // move closer along that row or column
+    // If the head is neither on the same row, nor or the same column as the tail,
+    // move closer diagonally
+    let dx = if head.x > tail.x {
+        1
+    } else if head.x < tail.x {
+        -1
+    } else {
+        0
+    };
+    let dy = if head.y > tail.y {
+        1
+    } else if head.y < tail.y {
+        -1
+    } else {
+        0
+    };
+    Some(Move::of(dx, dy))
+}
+
+/// Moves the head and tail by first moving the head, and then the tail follows.
+/// This function assumes the 'head_move' is a single-step move.
+pub fn move_head_and_tail(head: &Point, tail: &Point, head_move: &Move) -> (Point, Point) {
+    let new_head = head.translate_by_move(head_move);
+    let tail_move = calculate_tail_move(&new_head, tail);
+    let new_tail = match tail_move {
+        Some(mv) => tail.translate_by_move(&mv),
+        None => *tail,
+    };
+    (new_head, new_tail)
+}
+
+//noinspection RsBorrowChecker
+pub fn make_moves_and_count_tail_positions(moves: &Vec<Move>) -> i32 {
+    let mut head = ORIGO;
+    let mut tail = ORIGO;
+
+    let mut tail_positions: HashSet<Point> = HashSet::new();
+    tail_positions.insert(tail);
+
+    for mv in moves {
+        for smv in mv.split() {
+            // IntelliJ complains about this line, Cargo allows it
+            (head, tail) = move_head_and_tail(&head, &tail, &smv);
+            tail_positions.insert(tail);
+        }
+    }
+
+    tail_positions.len() as i32
+}
+
+/// Moves the entire rope by first moving the head according to 'head_move',
+/// and then the rest of the rope follows. This function assumes the 'head_move'
+/// is a single-step move.
+pub fn move_rope(rope: &[Point], head_move: &Move) -> Vec<Point> {
+    let mut new_rope: Vec<Point> = Vec::new();
+
+    // Move and add head
+    let mut segment_pos = rope[0].translate_by_move(head_move);
+    new_rope.push(segment_pos);
+
+    for i in 1..rope.len() {
+        let optional_move = calculate_tail_move(&segment_pos, &rope[i]);
+        if optional_move.is_none() {
+            // Add the rest of the segments unchanged
+            rope[i..].iter().for_each(|&p| new_rope.push(p));
+            break;
+        }
+
+        // Move and add segment
+        segment_pos = rope[i].translate_by_move(&optional_move.unwrap());
+        new_rope.push(segment_pos);
+    }
+
+    new_rope
+}
+
+pub fn make_moves_and_count_rope_end_positions(moves: &[Move], rope_len: usize) -> i32 {
+    make_split_moves_and_count_rope_end_positions(moves, rope_len, Split::AxisFirst)
+}
+
+/// Moves the rope like 'make_moves_and_count_rope_end_positions', but splits the
+/// moves of the head into single steps using 'strategy', which may move diagonally.
+pub fn make_split_moves_and_count_rope_end_positions(moves: &[Move], rope_len: usize, strategy: Split) -> i32 {
+    let mut rope = vec![ORIGO; rope_len];
+
+    let mut tail_positions: HashSet<Point> = HashSet::new();
+    tail_positions.insert(rope[rope.len() - 1]);
+
+    for mv in moves {
+        for smv in mv.split_with(strategy) {
+            rope = move_rope(&rope, &smv);
+            tail_positions.insert(rope[rope.len() - 1]);
+        }
+    }
+
+    tail_positions.len() as i32
+}
+
+/// The cells visited by a knot, stored as horizontal and vertical line segments, so that
+/// long straight moves take constant space.
+#[derive(Debug, Default, Clone)]
+pub struct VisitedCells {
+    rows: HashMap<i32, Vec<(i32, i32)>>,
+    columns: HashMap<i32, Vec<(i32, i32)>>,
+}
+
+impl VisitedCells {
+    /// Adds a single cell.
+    pub fn insert(&mut self, pos: Point) {
+        self.rows.entry(pos.y).or_default().push((pos.x, pos.x));
+    }
+
+    /// Adds all cells from 'from' to 'to', which are on the same row or the same column.
+    pub fn insert_segment(&mut self, from: Point, to: Point) {
+        if from.y == to.y {
+            self.rows.entry(from.y).or_default().push((from.x.min(to.x), from.x.max(to.x)));
+        } else {
+            assert_eq!(from.x, to.x, "segment is horizontal or vertical");
+            self.columns.entry(from.x).or_default().push((from.y.min(to.y), from.y.max(to.y)));
+        }
+    }
+
+    /// Returns the number of different cells visited. Takes O(n log n) time for n segments,
+    /// because the cells in both a row and a column are counted in one sweep over the rows.
+    pub fn count(&self) -> usize {
+        let rows: BTreeMap<i32, Vec<(i32, i32)>> = self.rows.iter()
+            .map(|(y, segments)| (*y, merge_segments(segments)))
+            .collect();
+        let columns: Vec<(i32, (i32, i32))> = self.columns.iter()
+            .flat_map(|(x, segments)| merge_segments(segments).into_iter().map(move |segment| (*x, segment)))
+            .collect();
+
+        let length = |(a, b): &(i32, i32)| (b - a + 1) as usize;
+        let total = rows.values().flatten().map(length).sum::<usize>() + columns.iter().map(|(_, s)| length(s)).sum::<usize>();
+
+        // The segments of a row or column are disjoint, so a cell in both a row and a
+        // column segment is the crossing of exactly one pair of segments. Going down the
+        // rows, each column segment is counted from its first row up to its last, and
+        // each row segment crosses the counted columns between its ends.
+        let mut xs: Vec<i32> = columns.iter().map(|(x, _)| *x).collect();
+        xs.sort();
+        xs.dedup();
+        let mut events: Vec<(i64, i64, usize)> = columns.iter()
+            .flat_map(|(x, (y1, y2))| {
+                let column = xs.binary_search(x).expect("column is in xs");
+                [(*y1 as i64, 1, column), (*y2 as i64 + 1, -1, column)]
+            })
+            .collect();
+        events.sort();
+
+        let mut active = PrefixSums::new(xs.len());
+        let mut events = events.into_iter().peekable();
+        let mut crossings = 0;
+        for (y, row) in &rows {
+            while let Some((_, change, column)) = events.next_if(|(event_y, _, _)| *event_y <= *y as i64) {
+                active.add(column, change);
+            }
+            for (x1, x2) in row {
+                let from = xs.partition_point(|x| x < x1);
+                let to = xs.partition_point(|x| x <= x2);
+                crossings += active.sum(to) - active.sum(from);
+            }
+        }
+        total - crossings as usize
+    }
+}
+
+/// Counts that can be changed and summed up to any index in O(log n) time, a Fenwick tree.
+struct PrefixSums {
+    tree: Vec<i64>,
+}
+
+impl PrefixSums {
+    fn new(len: usize) -> Self {
+        PrefixSums { tree: vec![0; len + 1] }
+    }
+
+    /// Adds 'change' to the count at 'index'.
+    fn add(&mut self, index: usize, change: i64) {
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] += change;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the sum of the counts before 'end'.
+    fn sum(&self, end: usize) -> i64 {
+        let mut sum = 0;
+        let mut i = end;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+}
+
+/// Returns the union of the inclusive 'segments' on a line as sorted, disjoint segments.
+fn merge_segments(segments: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let mut sorted = segments.to_vec();
+    sorted.sort();
+    let mut merged: Vec<(i32, i32)> = Vec::new();
+    for (a, b) in sorted {
+        match merged.last_mut() {
+            Some(last) if a <= last.1 + 1 => last.1 = last.1.max(b),
+            _ => merged.push((a, b)),
+        }
+    }
+    merged
+}
+
+/// Returns true if each knot of 'rope' is right behind the knot in front of it, seen
+/// in the direction of the single-step move 'step'.
+fn is_straight(rope: &[Point], step: &Move) -> bool {
+    rope.windows(2).all(|knots| knots[1] == knots[0].translate(-step.dx, -step.dy))
+}
+
+/// Gives the same result as 'make_moves_and_count_rope_end_positions', but without
+/// taking every step of a long move. Like 'Split::AxisFirst', each move is split into
+/// a horizontal run and then a vertical run. In each run, the rope moves one step at a
+/// time until it is straight behind the head, and then all knots jump to the end of the
+/// run at once. A rope straightens within a couple of steps per knot, so the time depends
+/// on the number of moves and the length of the rope, and not on the distance moved.
+pub fn make_long_moves_and_count_rope_end_positions(moves: &[Move], rope_len: usize) -> i32 {
+    let mut rope = vec![ORIGO; rope_len];
+    let mut visited = VisitedCells::default();
+    visited.insert(rope[rope_len - 1]);
+
+    for mv in moves {
+        let runs = [(Move::of(sgn(mv.dx), 0), mv.dx.abs()), (Move::of(0, sgn(mv.dy)), mv.dy.abs())];
+        for (step, mut remaining) in runs {
+            while remaining > 0 && !is_straight(&rope, &step) {
+                rope = move_rope(&rope, &step);
+                visited.insert(rope[rope_len - 1]);
+                remaining -= 1;
+            }
+            if remaining > 0 {
+                let tail = rope[rope_len - 1];
+                rope = rope.iter().map(|knot| knot.translate(step.dx * remaining, step.dy * remaining)).collect();
+                visited.insert_segment(tail.translate_by_move(&step), rope[rope_len - 1]);
+            }
+        }
+    }
+
+    visited.count() as i32
+}
+
+/// Returns the positions of all knots of a rope of 'rope_len' knots after each
+/// single-step move of the head, starting with all knots in the origin.
+pub fn get_trajectory(moves: &[Move], rope_len: usize) -> Vec<Vec<Point>> {
+    let mut rope = vec![ORIGO; rope_len];
+    let mut trajectory = vec![rope.clone()];
+    for smv in moves.iter().flat_map(Move::split) {
+        rope = move_rope(&rope, &smv);
+        trajectory.push(rope.clone());
+    }
+    trajectory
+}
+
+/// A knot of a rope in continuous space.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Knot {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Knot {
+    /// Creates a new knot from x and y.
+    pub const fn of(x: f64, y: f64) -> Self {
+        Knot { x, y }
+    }
+
+    /// Returns the distance between this knot and the other knot.
+    pub fn distance(&self, other: &Knot) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+
+    /// Returns the grid point nearest to this knot.
+    pub fn snap(&self) -> Point {
+        Point::of(self.x.round() as i32, self.y.round() as i32)
+    }
+}
+
+/// A rope in continuous space, where no segment is ever longer than 'segment_length':
+/// a knot stays where it is while the knot in front of it is within that distance, and
+/// is otherwise pulled straight towards it until the segment is exactly that long, like
+/// the forward pass of FABRIK. A segment can be slack, but is never stretched.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ContinuousRope {
+    pub knots: Vec<Knot>,
+    pub segment_length: f64,
+}
+
+impl ContinuousRope {
+    /// Creates a new rope of 'rope_len' knots, all in the origin.
+    pub fn new(rope_len: usize, segment_length: f64) -> Self {
+        ContinuousRope { knots: vec![Knot::of(0.0, 0.0); rope_len], segment_length }
+    }
+
+    /// Moves the head by 'dx' and 'dy', and then lets each knot follow the one in front of it.
+    pub fn move_head(&mut self, dx: f64, dy: f64) {
+        self.knots[0] = Knot::of(self.knots[0].x + dx, self.knots[0].y + dy);
+        for i in 1..self.knots.len() {
+            let (leader, knot) = (self.knots[i - 1], self.knots[i]);
+            let distance = leader.distance(&knot);
+            if distance <= self.segment_length {
+                // The rest of the rope is slack, and does not move
+                break;
+            }
+            let scale = self.segment_length / distance;
+            self.knots[i] = Knot::of(leader.x + (knot.x - leader.x) * scale, leader.y + (knot.y - leader.y) * scale);
+        }
+    }
+
+    /// Replays 'moves', moving the head one grid cell at a time in 'substeps' equal parts,
+    /// and returns the knots snapped to the grid after each cell, like 'get_trajectory'.
+    pub fn replay(&mut self, moves: &[Move], substeps: usize) -> Vec<Vec<Point>> {
+        let snap = |rope: &ContinuousRope| rope.knots.iter().map(Knot::snap).collect();
+        let mut trajectory = vec![snap(self)];
+        for smv in moves.iter().flat_map(Move::split) {
+            for _ in 0..substeps {
+                self.move_head(smv.dx as f64 / substeps as f64, smv.dy as f64 / substeps as f64);
+            }
+            trajectory.push(snap(self));
+        }
+        trajectory
+    }
+}
+
+/// How much two trajectories of the same moves differ.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct TrajectoryDiff {
+    /// The number of positions in each trajectory.
+    pub steps: usize,
+    /// The number of positions where the tails are in different grid cells.
+    pub differing_tails: usize,
+    /// The largest distance between the tails, counted in king moves on the grid.
+    pub max_tail_distance: i32,
+}
+
+/// Compares the tails of two trajectories of the same moves.
+pub fn compare_trajectories(a: &[Vec<Point>], b: &[Vec<Point>]) -> TrajectoryDiff {
+    assert_eq!(a.len(), b.len(), "trajectories have the same length");
+    let distances: Vec<i32> = a.iter().zip(b)
+        .map(|(a, b)| {
+            let (a, b) = (a.last().expect("rope has a tail"), b.last().expect("rope has a tail"));
+            (a.x - b.x).abs().max((a.y - b.y).abs())
+        })
+        .collect();
+    TrajectoryDiff {
+        steps: a.len(),
+        differing_tails: distances.iter().filter(|d| **d != 0).count(),
+        max_tail_distance: distances.into_iter().max().unwrap_or(0),
+    }
+}
+
+#[derive(Default)]
+pub struct Day09 {
+    moves: Vec<Move>,
+}
+
+impl Solution for Day09 {
+    fn parse(&mut self, input: &str) {
+        let lines: Vec<String> = lines(input.as_bytes()).collect();
+        self.moves = parse_moves(&to_vector_of_str(&lines));
+    }
+
+    fn part_1(&self) -> String {
+        make_long_moves_and_count_rope_end_positions(&self.moves, 2).to_string()
+    }
+
+    fn part_2(&self) -> String {
+        make_long_moves_and_count_rope_end_positions(&self.moves, 10).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::property::{check, shrink_i64, shrink_vec};
+    use crate::random::Random;
+    use super::*;
+    use crate::geom::Direction::{D, L, R, U};
+
+    #[test]
+    fn test_can_read_input() {
+        let input = read_input();
+        let vec = to_vector_of_str(&input);
+        assert_eq!(vec[0], "D 2");
+        assert_eq!(vec[1], "L 2");
+        assert_eq!(vec[2], "U 1");
+        let moves = parse_moves(&vec);
+        assert_eq!(moves[0], Move::of(0, -2));
+        assert_eq!(moves[1], Move::of(-2, 0));
+        assert_eq!(moves[2], Move::of(0, 1));
+    }
+
+    #[test]
+    fn test_can_parse_moves() {
+        let input = vec!["U 1", "D 75", "L 22", "R 0"];
+        let moves = parse_moves(&input);
+        assert_eq!(
+            moves,
+            vec![
+                Move::from_dir(U, 1),
+                Move::from_dir(D, 75),
+                Move::from_dir(L, 22),
+                Move::from_dir(R, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_can_calculate_tail_move() {
+        // Overlapping
+        assert_eq!(
+            calculate_tail_move(&Point::of(5, 9), &Point::of(5, 9)),
+            None
+        );
+        // Touching
+        assert_eq!(
+            calculate_tail_move(&Point::of(5, 9), &Point::of(6, 9)),
+            None
+        );
+        assert_eq!(
+            calculate_tail_move(&Point::of(5, 9), &Point::of(6, 8)),
+            None
+        );
+        assert_eq!(
+            calculate_tail_move(&Point::of(5, 9), &Point::of(4, 10)),
+            None
+        );
+        // Head is two steps up from tail
+        assert_eq!(
+            calculate_tail_move(&Point::of(5, 9), &Point::of(5, 7)),
+            Some(Move::of(0, 1))
+        );
+        // Head is two steps down from tail
+        assert_eq!(
+            calculate_tail_move(&Point::of(5, 9), &Point::of(5, 11)),
+            Some(Move::of(0, -1))
+        );
+        // Head is two steps left from tail
+        assert_eq!(
+            calculate_tail_move(&Point::of(5, 9), &Point::of(7, 9)),
+            Some(Move::of(-1, 0))
+        );
+        // Head is two steps right from tail
+        assert_eq!(
+            calculate_tail_move(&Point::of(5, 9), &Point::of(3, 9)),
+            Some(Move::of(1, 0))
+        );
+        // Head is two steps right and one step up
+        assert_eq!(
+            calculate_tail_move(&Point::of(5, 9), &Point::of(3, 8)),
+            Some(Move::of(1, 1))
+        );
+        // Head is two steps up and one step left
+        assert_eq!(
+            calculate_tail_move(&Point::of(5, 9), &Point::of(6, 7)),
+            Some(Move::of(-1, 1))
+        );
+    }
+
+    #[test]
+    fn test_can_move_head_and_tail() {
+        // Overlapping - tail does not move
+        let (head, tail) = move_head_and_tail(&Point::of(5, 9), &Point::of(5, 9), &Move::of(-1, 0));
+        assert_eq!((head, tail), (Point::of(4, 9), Point::of(5, 9)));
+
+        // Adjacent - head moves left - tail follows
+        let (head, tail) = move_head_and_tail(&Point::of(5, 9), &Point::of(6, 9), &Move::of(-1, 0));
+        assert_eq!((head, tail), (Point::of(4, 9), Point::of(5, 9)));
+
+        // Adjacent - head moves down - tail follows
+        let (head, tail) =
+            move_head_and_tail(&Point::of(5, 9), &Point::of(5, 10), &Move::of(0, -1));
+        assert_eq!((head, tail), (Point::of(5, 8), Point::of(5, 9)));
+
+        // Diagonal - head moves up - tail is still touching
+        let (head, tail) = move_head_and_tail(&Point::of(5, 9), &Point::of(6, 10), &Move::of(0, 1));
+        assert_eq!((head, tail), (Point::of(5, 10), Point::of(6, 10)));
+
+        // Diagonal - head moves left - tail moves diagonally
+        let (head, tail) =
+            move_head_and_tail(&Point::of(5, 9), &Point::of(6, 10), &Move::of(-1, 0));
+        assert_eq!((head, tail), (Point::of(4, 9), Point::of(5, 9)));
+
+        // Diagonal - head moves down - tail moves diagonally
+        let (head, tail) =
+            move_head_and_tail(&Point::of(5, 9), &Point::of(6, 10), &Move::of(0, -1));
+        assert_eq!((head, tail), (Point::of(5, 8), Point::of(5, 9)));
+    }
+
+    #[test]
+    fn test_move_and_count_single_complex_move() {
+        let input = vec!["U 4"];
+        let moves = parse_moves(&input);
+        let count = make_moves_and_count_tail_positions(&moves);
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn test_move_and_count_moves_right_and_left() {
+        let input = vec!["R 3", "L 5"];
+        let moves = parse_moves(&input);
+        let count = make_moves_and_count_tail_positions(&moves);
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    fn test_move_and_count_moves_down_and_left() {
+        let input = vec!["D 7", "L 5"];
+        let moves = parse_moves(&input);
+        let count = make_moves_and_count_tail_positions(&moves);
+        assert_eq!(count, 11);
+    }
+
+    #[test]
+    fn test_move_and_count_moves_down_left_up() {
+        let input = vec!["D 4", "L 5", "U 4"];
+        let moves = parse_moves(&input);
+        let count = make_moves_and_count_tail_positions(&moves);
+        assert_eq!(count, 11);
+    }
+
+    #[test]
+    fn test_move_and_count_moves_that_loop() {
+        let input = vec!["D 3", "L 3", "U 2", "R 5"];
+        let moves = parse_moves(&input);
+        let count = make_moves_and_count_tail_positions(&moves);
+        assert_eq!(count, 9);
+    }
+
+    const M_1_0: Move = Move::of(1, 0);
+    const M_0_1: Move = Move::of(0, 1);
+    const P_1_0: Point = Point::of(1, 0);
+    const P_2_0: Point = Point::of(2, 0);
+    const P_2_1: Point = Point::of(2, 1);
+    const P_3_0: Point = Point::of(3, 0);
+    const P_3_1: Point = Point::of(3, 1);
+    const P_3_2: Point = Point::of(3, 2);
+
+    #[test]
+    fn test_can_move_rope() {
+        let rope = vec![ORIGO, ORIGO, ORIGO];
+        let new_rope = move_rope(&rope, &M_1_0);
+        assert_eq!(new_rope, vec![P_1_0, ORIGO, ORIGO]);
+
+        let rope = new_rope;
+        let new_rope = move_rope(&rope, &M_1_0);
+        assert_eq!(new_rope, vec![P_2_0, P_1_0, ORIGO]);
+
+        let rope = new_rope;
+        let new_rope = move_rope(&rope, &M_1_0);
+        assert_eq!(new_rope, vec![P_3_0, P_2_0, P_1_0]);
+
+        let rope = new_rope;
+        let new_rope = move_rope(&rope, &M_0_1);
+        assert_eq!(new_rope, vec![P_3_1, P_2_0, P_1_0]);
+
+        let rope = new_rope;
+        let new_rope = move_rope(&rope, &M_0_1);
+        assert_eq!(new_rope, vec![P_3_2, P_3_1, P_2_1]);
+    }
+
+    #[test]
+    fn test_move_rope_and_count_single_complex_move() {
+        let input = vec!["U 4"];
+        let moves = parse_moves(&input);
+        let count = make_moves_and_count_rope_end_positions(&moves, 3);
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn test_move_rope_and_count_down_and_left() {
+        let input = vec!["D 3", "L 5"];
+        let moves = parse_moves(&input);
+        let count = make_moves_and_count_rope_end_positions(&moves, 3);
+        assert_eq!(count, 5);
+    }
+
+    #[test]
+    fn run_part_1_with_puzzle_input_using_rope_of_length_2() {
+        let input = read_input();
+        let vec = to_vector_of_str(&input);
+        let moves = parse_moves(&vec);
+        let count = make_moves_and_count_rope_end_positions(&moves, 2);
+        assert_eq!(count, 6098);
+    }
+
+    #[test]
+    fn run_part_1_with_puzzle_input() {
+        let input = read_input();
+        let vec = to_vector_of_str(&input);
+        let moves = parse_moves(&vec);
+        let count = make_moves_and_count_tail_positions(&moves);
+        assert_eq!(count, 6098);
+    }
+
+    #[test]
+    fn run_part_2_with_puzzle_input() {
+        let input = read_input();
+        let vec = to_vector_of_str(&input);
+        let moves = parse_moves(&vec);
+        let count = make_moves_and_count_rope_end_positions(&moves, 10);
+        assert_eq!(count, 2597);
+    }
+
+    #[test]
+    fn test_can_move_rope_diagonally() {
+        let moves = vec![Move::of(3, 3)];
+        assert_eq!(make_split_moves_and_count_rope_end_positions(&moves, 2, Split::DiagonalFirst), 3);
+        assert_eq!(make_split_moves_and_count_rope_end_positions(&moves, 2, Split::Interleaved), 3);
+        assert_eq!(make_split_moves_and_count_rope_end_positions(&moves, 2, Split::AxisFirst), 5);
+        assert_eq!(make_long_moves_and_count_rope_end_positions(&moves, 2), 5);
+    }
+
+    #[test]
+    fn test_can_count_visited_cells() {
+        let mut visited = VisitedCells::default();
+        visited.insert(Point::of(0, 0));
+        visited.insert(Point::of(0, 0));
+        visited.insert_segment(Point::of(-2, 0), Point::of(3, 0));
+        visited.insert_segment(Point::of(1, 5), Point::of(1, -5));
+        visited.insert_segment(Point::of(1, 2), Point::of(1, 7));
+        visited.insert(Point::of(1, 8));
+        // 6 cells on the row, 13 in the column, 1 shared, and 1 more below the column
+        assert_eq!(visited.count(), 19);
+    }
+
+    #[test]
+    fn property_visited_cells_agree_with_set() {
+        let random_segments = |random: &mut Random, size: usize| {
+            (0..random.range(0, size as i64))
+                .map(|_| {
+                    let from = Point::of(random.range(-5, 5) as i32, random.range(-5, 5) as i32);
+                    let length = random.range(-5, 5) as i32;
+                    let to = if random.chance(0.5) { from.translate(length, 0) } else { from.translate(0, length) };
+                    (from, to)
+                })
+                .collect::<Vec<(Point, Point)>>()
+        };
+        check(random_segments, |segments| shrink_vec(segments, |_| Vec::new()), |segments| {
+            let mut visited = VisitedCells::default();
+            let mut cells = HashSet::new();
+            for (from, to) in segments {
+                visited.insert_segment(*from, *to);
+                cells.extend(from.line_to(to));
+            }
+            visited.count() == cells.len()
+        });
+    }
+
+    #[test]
+    fn test_can_make_long_moves() {
+        let moves = parse_moves(&vec!["R 100000", "U 3", "L 99999", "D 1000", "R 4"]);
+        for rope_len in [1, 2, 10] {
+            assert_eq!(make_long_moves_and_count_rope_end_positions(&moves, rope_len),
+                       make_moves_and_count_rope_end_positions(&moves, rope_len));
+        }
+        let moves = parse_moves(&to_vector_of_str(&read_input()));
+        assert_eq!(make_long_moves_and_count_rope_end_positions(&moves, 10), 2597);
+    }
+
+    #[test]
+    fn test_can_get_trajectory() {
+        let trajectory = get_trajectory(&[Move::of(2, 0)], 3);
+        assert_eq!(trajectory, vec![
+            vec![ORIGO, ORIGO, ORIGO],
+            vec![Point::of(1, 0), ORIGO, ORIGO],
+            vec![Point::of(2, 0), Point::of(1, 0), ORIGO],
+        ]);
+    }
+
+    #[test]
+    fn test_continuous_rope_follows_in_straight_line() {
+        let moves = vec![Move::of(4, 0), Move::of(0, -3)];
+        let mut rope = ContinuousRope::new(2, 1.0);
+        let continuous = rope.replay(&moves, 1);
+        let discrete = get_trajectory(&moves, 2);
+        assert_eq!(continuous[..5], discrete[..5]);
+        assert_eq!(rope.knots[0], Knot::of(4.0, -3.0));
+        assert!((rope.knots[1].distance(&rope.knots[0]) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_continuous_rope_is_slack_within_segment_length() {
+        let mut rope = ContinuousRope::new(3, 1.5);
+        rope.move_head(1.0, 1.0);
+        assert_eq!(rope.knots[1], Knot::of(0.0, 0.0));
+        rope.move_head(1.0, 1.0);
+        let expected = 1.5 / 2.0_f64.sqrt();
+        assert!((rope.knots[1].x - (2.0 - expected)).abs() < 1e-9);
+        assert!((rope.knots[1].y - (2.0 - expected)).abs() < 1e-9);
+        assert_eq!(rope.knots[2], Knot::of(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_continuous_rope_follows_around_corner() {
+        let moves = vec![Move::of(3, 0), Move::of(0, 2)];
+        let continuous = ContinuousRope::new(3, 1.0).replay(&moves, 1);
+        let tails: Vec<Point> = continuous.iter().map(|rope| rope[2]).collect();
+        assert_eq!(tails, vec![ORIGO, ORIGO, ORIGO, Point::of(1, 0), Point::of(1, 0), Point::of(2, 0)]);
+        assert_eq!(continuous[5][1], Point::of(3, 1));
+
+        // The discrete tail moves diagonally at the corner, the continuous one lags behind
+        let discrete = get_trajectory(&moves, 3);
+        assert_eq!(discrete[5][2], Point::of(2, 1));
+        let diff = compare_trajectories(&discrete, &continuous);
+        assert_eq!(diff, TrajectoryDiff { steps: 6, differing_tails: 1, max_tail_distance: 1 });
+    }
+
+    #[test]
+    fn test_can_compare_trajectories() {
+        let moves = parse_moves(&to_vector_of_str(&read_input()));
+        let discrete = get_trajectory(&moves, 10);
+        assert_eq!(compare_trajectories(&discrete, &discrete).differing_tails, 0);
+
+        let continuous = ContinuousRope::new(10, 1.0).replay(&moves, 4);
+        let diff = compare_trajectories(&discrete, &continuous);
+        assert_eq!(diff.steps, discrete.len());
+        assert!(diff.differing_tails > 0);
+        assert!(diff.max_tail_distance > 0);
+    }
+
+    fn random_moves(random: &mut Random, size: usize) -> (usize, Vec<Move>) {
+        let rope_len = random.range(2, 10) as usize;
+        let moves = (0..random.range(0, size as i64))
+            .map(|_| Move::from_dir(random.choose(&[U, D, L, R]).clone(), random.range(0, 10) as i32))
+            .collect();
+        (rope_len, moves)
+    }
+
+    fn shrink_move(mv: &Move) -> Vec<Move> {
+        let distance = mv.dx + mv.dy;
+        shrink_i64(&(distance as i64)).into_iter()
+            .map(|d| Move::of(mv.dx.signum() * d.abs() as i32, mv.dy.signum() * d.abs() as i32))
+            .collect()
+    }
+
+    fn shrink_moves((rope_len, moves): &(usize, Vec<Move>)) -> Vec<(usize, Vec<Move>)> {
+        shrink_vec(moves, shrink_move).into_iter()
+            .map(|moves| (*rope_len, moves))
+            .chain((2..*rope_len).map(|len| (len, moves.clone())))
+            .collect()
+    }
+
+    #[test]
+    fn property_knots_touch_after_move_rope() {
+        check(random_moves, shrink_moves, |(rope_len, moves)| {
+            let mut rope = vec![ORIGO; *rope_len];
+            for smv in moves.iter().flat_map(Move::split) {
+                rope = move_rope(&rope, &smv);
+                if rope.windows(2).any(|knots| !knots[1].touches(&knots[0])) {
+                    return false;
+                }
+            }
+            true
+        });
+    }
+
+    #[test]
+    fn property_continuous_segments_are_never_stretched() {
+        check(random_moves, shrink_moves, |(rope_len, moves)| {
+            let mut rope = ContinuousRope::new(*rope_len, 1.0);
+            moves.iter().flat_map(Move::split).all(|smv| {
+                rope.move_head(smv.dx as f64 / 3.0, smv.dy as f64 / 3.0);
+                rope.knots.windows(2).all(|knots| knots[0].distance(&knots[1]) <= 1.0 + 1e-9)
+            })
+        });
+    }
+
+    #[test]
+    fn property_knots_touch_after_diagonal_moves() {
+        let diagonal_moves = |random: &mut Random, size: usize| {
+            let rope_len = random.range(2, 10) as usize;
+            let moves: Vec<Move> = (0..random.range(0, size as i64))
+                .map(|_| Move::of(random.range(-10, 10) as i32, random.range(-10, 10) as i32))
+                .collect();
+            (rope_len, moves)
+        };
+        check(diagonal_moves, shrink_moves, |(rope_len, moves)| {
+            [Split::Interleaved, Split::DiagonalFirst].iter().all(|strategy| {
+                let mut rope = vec![ORIGO; *rope_len];
+                moves.iter().flat_map(|mv| mv.split_with(*strategy)).all(|smv| {
+                    rope = move_rope(&rope, &smv);
+                    rope.windows(2).all(|knots| knots[1].touches(&knots[0]))
+                })
+            })
+        });
+    }
+
+    #[test]
+    fn property_long_moves_agree_with_single_steps() {
+        check(random_moves, shrink_moves, |(rope_len, moves)| {
+            make_long_moves_and_count_rope_end_positions(moves, *rope_len) ==
+                make_moves_and_count_rope_end_positions(moves, *rope_len)
+        });
+    }
+
+    #[test]
+    fn property_rope_of_length_2_agrees_with_head_and_tail() {
+        check(random_moves, shrink_moves, |(_, moves)| {
+            make_moves_and_count_rope_end_positions(moves, 2) == make_moves_and_count_tail_positions(moves)
+        });
+    }
+}
@@ -0,0 +1,44 @@
+use crate::solution::Solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+
+/// The days that have a solution, in order.
+pub const DAYS: &[u32] = &[1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+/// Returns a new, unparsed solution for 'day', or None if that day is not implemented.
+pub fn get_solution(day: u32) -> Option<Box<dyn Solution>> {
+    match day {
+        1 => Some(Box::<day01::Day01>::default()),
+        2 => Some(Box::<day02::Day02>::default()),
+        3 => Some(Box::<day03::Day03>::default()),
+        4 => Some(Box::<day04::Day04>::default()),
+        5 => Some(Box::<day05::Day05>::default()),
+        6 => Some(Box::<day06::Day06>::default()),
+        7 => Some(Box::<day07::Day07>::default()),
+        8 => Some(Box::<day08::Day08>::default()),
+        9 => Some(Box::<day09::Day09>::default()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_get_solution() {
+        for &day in DAYS {
+            assert!(get_solution(day).is_some());
+        }
+        assert!(get_solution(0).is_none());
+        assert!(get_solution(25).is_none());
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use crate::geom::Point;
+
+/// Partitions values into disjoint sets, the components, that can be merged.
+/// Uses union by rank and path compression, so that any sequence of operations
+/// takes nearly constant time per operation.
+#[derive(Debug, Clone)]
+pub struct DisjointSet<K> {
+    indices: HashMap<K, usize>,
+    keys: Vec<K>,
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl<K: Hash + Eq + Clone> Default for DisjointSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone> DisjointSet<K> {
+    pub fn new() -> Self {
+        DisjointSet {
+            indices: HashMap::new(),
+            keys: Vec::new(),
+            parents: Vec::new(),
+            ranks: Vec::new(),
+            sizes: Vec::new(),
+            components: 0,
+        }
+    }
+
+    /// Adds 'key' in a component of its own, and returns true if it was not added before.
+    pub fn insert(&mut self, key: K) -> bool {
+        if self.indices.contains_key(&key) {
+            return false;
+        }
+        let index = self.keys.len();
+        self.indices.insert(key.clone(), index);
+        self.keys.push(key);
+        self.parents.push(index);
+        self.ranks.push(0);
+        self.sizes.push(1);
+        self.components += 1;
+        true
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the number of components.
+    pub fn count_components(&self) -> usize {
+        self.components
+    }
+
+    /// Returns the index of the root of the component with the value at 'index',
+    /// and points all values on the way directly at the root.
+    fn find_root(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut index = index;
+        while self.parents[index] != root {
+            let next = self.parents[index];
+            self.parents[index] = root;
+            index = next;
+        }
+        root
+    }
+
+    /// Returns the representative of the component of 'key', which is the same value for
+    /// all values in the component until it is merged, or None if 'key' was never added.
+    pub fn find(&mut self, key: &K) -> Option<K> {
+        let index = *self.indices.get(key)?;
+        let root = self.find_root(index);
+        Some(self.keys[root].clone())
+    }
+
+    /// Merges the components of 'a' and 'b', adding them first if needed, and returns
+    /// true if they were in different components.
+    pub fn union(&mut self, a: &K, b: &K) -> bool {
+        self.insert(a.clone());
+        self.insert(b.clone());
+        let a = self.find_root(self.indices[a]);
+        let b = self.find_root(self.indices[b]);
+        if a == b {
+            return false;
+        }
+        let (parent, child) = if self.ranks[a] < self.ranks[b] { (b, a) } else { (a, b) };
+        self.parents[child] = parent;
+        self.sizes[parent] += self.sizes[child];
+        if self.ranks[parent] == self.ranks[child] {
+            self.ranks[parent] += 1;
+        }
+        self.components -= 1;
+        true
+    }
+
+    /// Returns true if 'a' and 'b' were added and are in the same component.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.indices.get(a).copied(), self.indices.get(b).copied()) {
+            (Some(a), Some(b)) => self.find_root(a) == self.find_root(b),
+            _ => false,
+        }
+    }
+
+    /// Returns the number of values in the component of 'key', or 0 if it was never added.
+    pub fn component_size(&mut self, key: &K) -> usize {
+        match self.indices.get(key).copied() {
+            Some(index) => {
+                let root = self.find_root(index);
+                self.sizes[root]
+            }
+            None => 0,
+        }
+    }
+
+    /// Returns the values in the component of 'key', in the order they were added.
+    pub fn members(&mut self, key: &K) -> Vec<K> {
+        let Some(index) = self.indices.get(key).copied() else {
+            return Vec::new();
+        };
+        let root = self.find_root(index);
+        let indices: Vec<usize> = (0..self.keys.len()).filter(|i| self.find_root(*i) == root).collect();
+        indices.into_iter().map(|i| self.keys[i].clone()).collect()
+    }
+
+    /// Returns the values of each component, in the order they were added, with the
+    /// components in the order their first value was added.
+    pub fn components(&mut self) -> Vec<Vec<K>> {
+        let mut components: Vec<Vec<K>> = Vec::new();
+        let mut positions: HashMap<usize, usize> = HashMap::new();
+        for index in 0..self.keys.len() {
+            let root = self.find_root(index);
+            let position = *positions.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[position].push(self.keys[index].clone());
+        }
+        components
+    }
+}
+
+/// Splits 'points' into clusters, where two points are in the same cluster if they are
+/// connected by a chain of points that are 'adjacent', like points that touch each other.
+/// The clusters are in the order of their first point, row by row.
+pub fn cluster(points: &HashSet<Point>, adjacent: impl Fn(&Point, &Point) -> bool) -> Vec<HashSet<Point>> {
+    let mut sorted: Vec<Point> = points.iter().copied().collect();
+    sorted.sort_by_key(|p| (p.y, p.x));
+    let mut set = DisjointSet::new();
+    for (i, a) in sorted.iter().enumerate() {
+        set.insert(*a);
+        for b in &sorted[i + 1..] {
+            if adjacent(a, b) {
+                set.union(a, b);
+            }
+        }
+    }
+    set.components()
+        .into_iter()
+        .map(|component| component.into_iter().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{label_components, Connectivity, Grid};
+    use crate::property::{check, no_shrink};
+    use crate::random::Random;
+
+    #[test]
+    fn test_can_union_and_find() {
+        let mut set: DisjointSet<&str> = DisjointSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert("a"));
+        assert!(!set.insert("a"));
+        assert_eq!(set.find(&"a"), Some("a"));
+        assert_eq!(set.find(&"z"), None);
+
+        assert!(set.union(&"a", &"b"));
+        assert!(set.union(&"c", &"d"));
+        assert!(!set.union(&"e", &"e"));
+        assert_eq!(set.len(), 5);
+        assert_eq!(set.count_components(), 3);
+        assert!(set.connected(&"a", &"b"));
+        assert!(!set.connected(&"a", &"c"));
+        assert!(!set.connected(&"a", &"z"));
+
+        assert!(set.union(&"b", &"d"));
+        assert!(!set.union(&"a", &"c"));
+        assert_eq!(set.count_components(), 2);
+        assert_eq!(set.find(&"a"), set.find(&"d"));
+        assert_eq!(set.component_size(&"c"), 4);
+        assert_eq!(set.component_size(&"e"), 1);
+        assert_eq!(set.component_size(&"z"), 0);
+        assert_eq!(set.members(&"d"), vec!["a", "b", "c", "d"]);
+        assert_eq!(set.components(), vec![vec!["a", "b", "c", "d"], vec!["e"]]);
+    }
+
+    #[test]
+    fn test_can_cluster_touching_points() {
+        let points = HashSet::from([
+            Point::of(0, 0), Point::of(1, 1), Point::of(2, 2),
+            Point::of(5, 0), Point::of(5, 1),
+            Point::of(9, 9),
+        ]);
+        let clusters = cluster(&points, Point::touches);
+        assert_eq!(clusters.len(), 3);
+        assert_eq!(clusters[0], HashSet::from([Point::of(0, 0), Point::of(1, 1), Point::of(2, 2)]));
+        assert_eq!(clusters[1], HashSet::from([Point::of(5, 0), Point::of(5, 1)]));
+        assert_eq!(clusters[2], HashSet::from([Point::of(9, 9)]));
+
+        let far = |a: &Point, b: &Point| (a.x - b.x).abs() + (a.y - b.y).abs() <= 5;
+        assert_eq!(cluster(&points, far).len(), 2);
+    }
+
+    fn random_points(random: &mut Random, size: usize) -> HashSet<Point> {
+        (0..size)
+            .map(|_| Point::of(random.range(0, 10) as i32, random.range(0, 10) as i32))
+            .collect()
+    }
+
+    #[test]
+    fn property_clusters_agree_with_components() {
+        check(random_points, no_shrink, |points| {
+            let components = label_components(points, Connectivity::Eight, |_, _| true);
+            let clusters = cluster(points, Point::touches);
+            clusters.len() == components.len()
+                && clusters.iter().enumerate().all(|(label, c)| *c == components.get_region(label))
+                && points.cells().len() == clusters.iter().map(HashSet::len).sum::<usize>()
+        });
+    }
+}
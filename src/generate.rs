@@ -0,0 +1,486 @@
+use std::collections::HashSet;
+use crate::random::Random;
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A generated puzzle input, and the answers to it computed by a reference implementation.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Generated {
+    pub input: String,
+    pub part_1: String,
+    pub part_2: String,
+}
+
+impl Generated {
+    fn of(input: String, part_1: impl ToString, part_2: impl ToString) -> Self {
+        Generated { input, part_1: part_1.to_string(), part_2: part_2.to_string() }
+    }
+}
+
+/// Generates a random, valid input for 'day' from 'seed'. The 'size' is the number of
+/// items in the input, e.g. elves, rounds, moves, files, or trees, depending on the day.
+/// Some days round it to a valid shape: rucksacks come in whole groups of three, and the
+/// forest is square. Returns None if there is no generator for the day.
+pub fn generate(day: u32, seed: u64, size: usize) -> Option<Generated> {
+    let mut random = Random::new(seed);
+    let size = size.max(1);
+    match day {
+        1 => Some(generate_calories(&mut random, size)),
+        2 => Some(generate_rounds(&mut random, size)),
+        3 => Some(generate_rucksacks(&mut random, size)),
+        4 => Some(generate_section_pairs(&mut random, size)),
+        5 => Some(generate_crane_moves(&mut random, size)),
+        6 => Some(generate_datastream(&mut random, size)),
+        7 => Some(generate_transcript(&mut random, size)),
+        8 => Some(generate_forest(&mut random, size)),
+        9 => Some(generate_rope_moves(&mut random, size)),
+        _ => None,
+    }
+}
+
+/// Returns 'count' random chars from 'alphabet'.
+fn random_chars(random: &mut Random, alphabet: &[char], count: usize) -> Vec<char> {
+    (0..count).map(|_| *random.choose(alphabet)).collect()
+}
+
+/// Returns 'alphabet' in random order.
+fn shuffled(random: &mut Random, alphabet: &str) -> Vec<char> {
+    let mut chars: Vec<char> = alphabet.chars().collect();
+    random.shuffle(&mut chars);
+    chars
+}
+
+/// Day 1: groups of calories, one group per elf.
+fn generate_calories(random: &mut Random, elves: usize) -> Generated {
+    let mut groups: Vec<String> = Vec::new();
+    let mut sums: Vec<i64> = Vec::new();
+    for _ in 0..elves {
+        let count = random.range(1, 15);
+        let items: Vec<i64> = (0..count).map(|_| random.range(1000, 60_000)).collect();
+        sums.push(items.iter().sum());
+        groups.push(items.iter().map(|i| i.to_string()).collect::<Vec<String>>().join("\n"));
+    }
+    sums.sort_by(|a, b| b.cmp(a));
+    Generated::of(groups.join("\n\n") + "\n", sums[0], sums.iter().take(3).sum::<i64>())
+}
+
+/// Day 2: rounds of rock paper scissors.
+fn generate_rounds(random: &mut Random, rounds: usize) -> Generated {
+    let mut lines: Vec<String> = Vec::new();
+    let (mut part_1, mut part_2) = (0, 0);
+    for _ in 0..rounds {
+        let (opponent, column) = (random.index(3), random.index(3));
+        lines.push(format!("{} {}", b"ABC"[opponent] as char, b"XYZ"[column] as char));
+        // Part 1: the column is my shape, and (mine - theirs) mod 3 is 0 for draw, 1 for win, 2 for loss
+        part_1 += column + 1 + (column + 4 - opponent) % 3 * 3;
+        // Part 2: the column is the outcome, 0 for loss, 1 for draw, 2 for win
+        part_2 += (opponent + column + 2) % 3 + 1 + column * 3;
+    }
+    Generated::of(lines.join("\n") + "\n", part_1, part_2)
+}
+
+fn priority(c: char) -> usize {
+    LETTERS.find(c).expect("c is a letter") + 1
+}
+
+/// Day 3: rucksacks in groups of three. The two halves of each rucksack share exactly
+/// one item, and the three rucksacks of each group share exactly one item, the badge.
+/// Part 2 needs whole groups, so 'rucksacks' is rounded up to a multiple of three.
+fn generate_rucksacks(random: &mut Random, rucksacks: usize) -> Generated {
+    let mut lines: Vec<String> = Vec::new();
+    let (mut part_1, mut part_2) = (0, 0);
+    for _ in 0..rucksacks.div_ceil(3) {
+        // Each elf in the group gets its own letters, so only the badge is common to all
+        let letters = shuffled(random, LETTERS);
+        let badge = letters[0];
+        part_2 += priority(badge);
+
+        for pool in letters[1..].chunks(17) {
+            let shared = if random.chance(0.1) { badge } else { pool[0] };
+            part_1 += priority(shared);
+
+            // The rest of the letters are split in left-only and right-only letters
+            let others: Vec<char> = pool.iter().filter(|c| **c != shared).copied().collect();
+            let (left_only, right_only) = others.split_at(others.len() / 2);
+            let half_len = random.range(2, 16) as usize;
+
+            let mut left = vec![shared];
+            if shared != badge {
+                left.push(badge);
+            }
+            let missing = half_len - left.len();
+            left.extend(random_chars(random, left_only, missing));
+            let mut right = vec![shared];
+            right.extend(random_chars(random, right_only, half_len - 1));
+            random.shuffle(&mut left);
+            random.shuffle(&mut right);
+            lines.push(left.into_iter().chain(right).collect());
+        }
+    }
+    Generated::of(lines.join("\n") + "\n", part_1, part_2)
+}
+
+/// Day 4: pairs of section intervals.
+fn generate_section_pairs(random: &mut Random, pairs: usize) -> Generated {
+    let mut lines: Vec<String> = Vec::new();
+    let (mut part_1, mut part_2) = (0, 0);
+    for _ in 0..pairs {
+        let from_1 = random.range(1, 99);
+        let to_1 = random.range(from_1, 99);
+        let from_2 = random.range(1, 99);
+        let to_2 = random.range(from_2, 99);
+        lines.push(format!("{}-{},{}-{}", from_1, to_1, from_2, to_2));
+        // One interval contains the other if they do not stick out in opposite directions
+        if (from_1 - from_2) * (to_1 - to_2) <= 0 {
+            part_1 += 1;
+        }
+        if from_1.max(from_2) <= to_1.min(to_2) {
+            part_2 += 1;
+        }
+    }
+    Generated::of(lines.join("\n") + "\n", part_1, part_2)
+}
+
+/// Renders the stacks as a drawing in the puzzle format, including the stack numbers.
+pub fn draw_stacks(stacks: &[Vec<char>]) -> Vec<String> {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines: Vec<String> = (0..height).rev()
+        .map(|level| stacks.iter()
+            .map(|stack| stack.get(level).map(|c| format!("[{}]", c)).unwrap_or_else(|| String::from("   ")))
+            .collect::<Vec<String>>()
+            .join(" "))
+        .collect();
+    lines.push((1..=stacks.len()).map(|n| format!(" {} ", n)).collect::<Vec<String>>().join(" "));
+    lines
+}
+
+/// Day 5: a drawing of crate stacks, and a list of moves that are legal for both cranes.
+fn generate_crane_moves(random: &mut Random, moves: usize) -> Generated {
+    let uppercase: Vec<char> = LETTERS[26..].chars().collect();
+    let stack_count = random.range(2, 9) as usize;
+    let mut stacks_1: Vec<Vec<char>> = (0..stack_count)
+        .map(|_| {
+            let height = random.range(0, 8) as usize;
+            random_chars(random, &uppercase, height)
+        })
+        .collect();
+    if stacks_1.iter().all(Vec::is_empty) {
+        stacks_1[0].push(*random.choose(&uppercase));
+    }
+
+    let mut lines = draw_stacks(&stacks_1);
+    lines.push(String::new());
+
+    // Both cranes move the same number of crates, so the stack heights stay the same
+    let mut stacks_2 = stacks_1.clone();
+    for _ in 0..moves {
+        let non_empty: Vec<usize> = (0..stack_count).filter(|i| !stacks_1[*i].is_empty()).collect();
+        let from = *random.choose(&non_empty);
+        let to = (from + random.range(1, stack_count as i64 - 1) as usize) % stack_count;
+        let count = random.range(1, stacks_1[from].len() as i64) as usize;
+        lines.push(format!("move {} from {} to {}", count, from + 1, to + 1));
+
+        for _ in 0..count {
+            let c = stacks_1[from].pop().expect("stack is not empty");
+            stacks_1[to].push(c);
+        }
+        let split = stacks_2[from].len() - count;
+        let mut moved = stacks_2[from].split_off(split);
+        stacks_2[to].append(&mut moved);
+    }
+
+    let top = |stacks: &[Vec<char>]| stacks.iter().filter_map(|s| s.last()).collect::<String>();
+    Generated::of(lines.join("\n") + "\n", top(&stacks_1), top(&stacks_2))
+}
+
+/// Returns the number of chars processed when the first 'len' distinct chars have been seen,
+/// by checking every window, or None if there is no marker. The reference for day 6.
+pub fn find_marker_naively(chars: &[char], len: usize) -> Option<usize> {
+    (len..=chars.len()).find(|end| {
+        let window = &chars[end - len..*end];
+        window.iter().all(|c| window.iter().filter(|d| *d == c).count() == 1)
+    })
+}
+
+/// Day 6: a datastream with planted start-of-packet and start-of-message markers.
+fn generate_datastream(random: &mut Random, len: usize) -> Generated {
+    let letters = shuffled(random, LOWERCASE);
+    // Before the message marker only 13 letters are used, so no earlier window of 14
+    // letters can be a message marker, and before the packet marker only 3 letters
+    // are used, so no earlier window of 4 letters can be a packet marker
+    let (few, some) = (&letters[..3], &letters[..13]);
+
+    let prefix_len = random.range(0, len as i64 / 3) as usize;
+    let mut chars = random_chars(random, few, prefix_len);
+    let mut packet_marker = some.to_vec();
+    random.shuffle(&mut packet_marker);
+    chars.extend(&packet_marker[..4]);
+    let middle_len = random.range(0, len as i64 / 3) as usize;
+    chars.extend(random_chars(random, some, middle_len));
+    let mut message_marker = letters.clone();
+    random.shuffle(&mut message_marker);
+    chars.extend(&message_marker[..14]);
+    let tail_len = len.saturating_sub(chars.len()).max(1);
+    chars.extend(random_chars(random, &letters, tail_len));
+
+    let part_1 = find_marker_naively(&chars, 4).expect("datastream has a packet marker");
+    let part_2 = find_marker_naively(&chars, 14).expect("datastream has a message marker");
+    Generated::of(chars.into_iter().collect::<String>() + "\n", part_1, part_2)
+}
+
+/// A directory in a generated file system. Parents are always created before their children.
+#[derive(Debug)]
+pub struct GeneratedDir {
+    pub name: String,
+    pub parent: Option<usize>,
+    pub dirs: Vec<usize>,
+    pub files: Vec<(String, i64)>,
+}
+
+/// Returns a random name that is not in 'taken', and adds it to 'taken'.
+fn unique_name(random: &mut Random, taken: &mut HashSet<String>, extension: bool) -> String {
+    let lowercase: Vec<char> = LOWERCASE.chars().collect();
+    loop {
+        let len = random.range(1, 8) as usize;
+        let mut name: String = random_chars(random, &lowercase, len).into_iter().collect();
+        if extension && random.chance(0.5) {
+            name.push('.');
+            name.extend(random_chars(random, &lowercase, 3));
+        }
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// Generates a random file system with 'files' files, where the total size of all files
+/// is between 45 and 65 million, so a directory must be deleted to free up space.
+pub fn generate_file_system(random: &mut Random, files: usize) -> Vec<GeneratedDir> {
+    let mut dirs = vec![GeneratedDir { name: String::from("/"), parent: None, dirs: vec![], files: vec![] }];
+    let mut names: Vec<HashSet<String>> = vec![HashSet::new()];
+
+    for _ in 1..files.div_ceil(4).max(1) {
+        let parent = random.index(dirs.len());
+        let name = unique_name(random, &mut names[parent], false);
+        let index = dirs.len();
+        dirs[parent].dirs.push(index);
+        dirs.push(GeneratedDir { name, parent: Some(parent), dirs: vec![], files: vec![] });
+        names.push(HashSet::new());
+    }
+
+    let weights: Vec<i64> = (0..files).map(|_| random.range(1, 1000)).collect();
+    let total_weight: i64 = weights.iter().sum();
+    let total_size = random.range(45_000_000, 65_000_000);
+    for weight in weights {
+        let dir = random.index(dirs.len());
+        let name = unique_name(random, &mut names[dir], true);
+        dirs[dir].files.push((name, total_size * weight / total_weight + 1));
+    }
+    dirs
+}
+
+/// Returns the total size of each directory, including the sizes of all subdirectories.
+pub fn get_total_sizes(dirs: &[GeneratedDir]) -> Vec<i64> {
+    let mut sizes: Vec<i64> = dirs.iter().map(|d| d.files.iter().map(|f| f.1).sum()).collect();
+    // Children always come after their parents
+    for i in (1..dirs.len()).rev() {
+        let parent = dirs[i].parent.expect("only the root has no parent");
+        sizes[parent] += sizes[i];
+    }
+    sizes
+}
+
+fn write_listing(random: &mut Random, dirs: &[GeneratedDir], dir: usize, lines: &mut Vec<String>) {
+    lines.push(String::from("$ ls"));
+    let mut entries: Vec<String> = dirs[dir].dirs.iter()
+        .map(|d| format!("dir {}", dirs[*d].name))
+        .chain(dirs[dir].files.iter().map(|(name, size)| format!("{} {}", size, name)))
+        .collect();
+    random.shuffle(&mut entries);
+    lines.extend(entries);
+
+    let mut children = dirs[dir].dirs.clone();
+    random.shuffle(&mut children);
+    for child in children {
+        lines.push(format!("$ cd {}", dirs[child].name));
+        write_listing(random, dirs, child, lines);
+        lines.push(String::from("$ cd .."));
+    }
+}
+
+/// Writes a terminal transcript that lists every directory of the file system exactly
+/// once, in random order.
+pub fn write_transcript(random: &mut Random, dirs: &[GeneratedDir]) -> Vec<String> {
+    let mut lines = vec![String::from("$ cd /")];
+    write_listing(random, dirs, 0, &mut lines);
+    lines
+}
+
+/// Returns the path of directory 'dir', like "/a/e", or "/" for the root.
+pub fn get_dir_path(dirs: &[GeneratedDir], dir: usize) -> String {
+    match dirs[dir].parent {
+        None => String::from("/"),
+        Some(0) => format!("/{}", dirs[dir].name),
+        Some(parent) => format!("{}/{}", get_dir_path(dirs, parent), dirs[dir].name),
+    }
+}
+
+/// Day 7: a terminal transcript that lists every directory of a file system exactly once.
+fn generate_transcript(random: &mut Random, files: usize) -> Generated {
+    let dirs = generate_file_system(random, files);
+    let lines = write_transcript(random, &dirs);
+
+    let sizes = get_total_sizes(&dirs);
+    let part_1: i64 = sizes.iter().filter(|s| **s <= 100_000).sum();
+    let missing = 30_000_000 - (70_000_000 - sizes[0]);
+    let part_2 = sizes.iter().filter(|s| **s >= missing).min().expect("root is big enough");
+    Generated::of(lines.join("\n") + "\n", part_1, part_2)
+}
+
+/// Day 8: a square forest of random tree heights.
+fn generate_forest(random: &mut Random, trees: usize) -> Generated {
+    let side = ((trees as f64).sqrt().round() as usize).max(2);
+    let mut forest: Vec<Vec<i64>> = vec![vec![0; side]; side];
+    forest.iter_mut().flatten().for_each(|height| *height = random.range(0, 9));
+
+    let directions = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+    let mut visible = 0;
+    let mut max_score = 0;
+    for y in 0..side as i64 {
+        for x in 0..side as i64 {
+            let height = forest[y as usize][x as usize];
+            let mut is_visible = false;
+            let mut score = 1;
+            for (dx, dy) in directions {
+                let line: Vec<i64> = (1..)
+                    .map(|n| (x + n * dx, y + n * dy))
+                    .take_while(|(x, y)| *x >= 0 && *y >= 0 && *x < side as i64 && *y < side as i64)
+                    .map(|(x, y)| forest[y as usize][x as usize])
+                    .collect();
+                is_visible |= line.iter().all(|h| *h < height);
+                score *= line.iter().position(|h| *h >= height).map(|p| p + 1).unwrap_or(line.len());
+            }
+            visible += is_visible as usize;
+            max_score = max_score.max(score);
+        }
+    }
+
+    let lines: Vec<String> = forest.iter()
+        .map(|row| row.iter().map(|h| h.to_string()).collect())
+        .collect();
+    Generated::of(lines.join("\n") + "\n", visible, max_score)
+}
+
+/// Day 9: a list of rope moves.
+fn generate_rope_moves(random: &mut Random, moves: usize) -> Generated {
+    let mut lines: Vec<String> = Vec::new();
+    let mut knots = [(0i64, 0i64); 10];
+    let mut visited_1: HashSet<(i64, i64)> = HashSet::from([(0, 0)]);
+    let mut visited_9: HashSet<(i64, i64)> = HashSet::from([(0, 0)]);
+    for _ in 0..moves {
+        let (direction, dx, dy) = *random.choose(&[('U', 0, 1), ('D', 0, -1), ('L', -1, 0), ('R', 1, 0)]);
+        let distance = random.range(1, 20);
+        lines.push(format!("{} {}", direction, distance));
+        for _ in 0..distance {
+            knots[0] = (knots[0].0 + dx, knots[0].1 + dy);
+            for i in 1..knots.len() {
+                let (x, y) = (knots[i - 1].0 - knots[i].0, knots[i - 1].1 - knots[i].1);
+                if x.abs() > 1 || y.abs() > 1 {
+                    knots[i] = (knots[i].0 + x.signum(), knots[i].1 + y.signum());
+                }
+            }
+            visited_1.insert(knots[1]);
+            visited_9.insert(knots[9]);
+        }
+    }
+    Generated::of(lines.join("\n") + "\n", visited_1.len(), visited_9.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::{get_solution, DAYS};
+    use crate::lines;
+    use super::*;
+
+    #[test]
+    fn test_same_seed_gives_same_input() {
+        // New days have no generator until one is written
+        for &day in DAYS.iter().filter(|day| generate(**day, 42, 50).is_some()) {
+            assert_eq!(generate(day, 42, 50), generate(day, 42, 50));
+            assert_ne!(generate(day, 42, 50), generate(day, 43, 50));
+        }
+        assert_eq!(generate(25, 42, 50), None);
+    }
+
+    #[test]
+    fn test_solutions_agree_with_reference() {
+        for &day in DAYS {
+            for (seed, size) in [(1, 1), (2, 10), (3, 100), (4, 1000)] {
+                let Some(generated) = generate(day, seed, size) else {
+                    continue;
+                };
+                let mut solution = get_solution(day).unwrap();
+                solution.parse(&generated.input);
+                assert_eq!(solution.part_1(), generated.part_1, "day {} seed {} part 1", day, seed);
+                assert_eq!(solution.part_2(), generated.part_2, "day {} seed {} part 2", day, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rucksacks_share_exactly_one_item() {
+        let generated = generate(3, 7, 300).unwrap();
+        let rucksacks: Vec<String> = lines(generated.input.as_bytes()).collect();
+        assert_eq!(rucksacks.len(), 300);
+        let set = |s: &str| s.chars().collect::<HashSet<char>>();
+        for rucksack in &rucksacks {
+            let (left, right) = rucksack.split_at(rucksack.len() / 2);
+            assert_eq!(set(left).intersection(&set(right)).count(), 1, "{}", rucksack);
+        }
+        for group in rucksacks.chunks(3) {
+            let common: HashSet<char> = set(&group[0]).intersection(&set(&group[1])).copied().collect();
+            assert_eq!(common.intersection(&set(&group[2])).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_rucksacks_are_rounded_up_to_whole_groups() {
+        let count = |size| lines(generate(3, 7, size).unwrap().input.as_bytes()).count();
+        assert_eq!(count(1), 3);
+        assert_eq!(count(10), 12);
+        assert_eq!(count(12), 12);
+    }
+
+    #[test]
+    fn test_can_draw_stacks() {
+        let drawing = draw_stacks(&[vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+        assert_eq!(drawing, vec![
+            "    [D]    ",
+            "[N] [C]    ",
+            "[Z] [M] [P]",
+            " 1   2   3 ",
+        ]);
+    }
+
+    #[test]
+    fn test_can_find_marker_naively() {
+        let chars: Vec<char> = "mjqjpqmgbljsphdztnvjfqwrcgsmlb".chars().collect();
+        assert_eq!(find_marker_naively(&chars, 4), Some(7));
+        assert_eq!(find_marker_naively(&chars, 14), Some(19));
+        assert_eq!(find_marker_naively(&chars[..6], 4), None);
+    }
+
+    #[test]
+    fn test_file_system_is_consistent() {
+        let mut random = Random::new(3);
+        let dirs = generate_file_system(&mut random, 200);
+        assert_eq!(dirs.iter().map(|d| d.files.len()).sum::<usize>(), 200);
+        let sizes = get_total_sizes(&dirs);
+        assert!(sizes[0] > 45_000_000 && sizes[0] < 70_000_000);
+        for (i, dir) in dirs.iter().enumerate() {
+            let children: i64 = dir.dirs.iter().map(|d| sizes[*d]).sum();
+            assert!(sizes[i] >= children);
+        }
+    }
+}
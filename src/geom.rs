@@ -1,4 +1,5 @@
 use crate::geom::Direction::{D, L, R, U};
+use crate::num::gcd;
 use crate::to_i32;
 
 /// Returns the sign of 'value': -1, 0, or 1.
@@ -23,7 +24,7 @@ impl Direction {
             'L' => Some(L),
             'R' => Some(R),
             _ => None
-        }.expect(&*format!("Direction is U, D, L, or R: {}", c))
+        }.unwrap_or_else(|| panic!("Direction is U, D, L, or R: {}", c))
     }
 
     pub const fn dx(&self) -> i32 {
@@ -60,6 +61,7 @@ impl Move {
     }
 
     /// Creates a new Move from a string like "L 7".
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(text: &str) -> Self {
         let parts: Vec<&str> = text.split_ascii_whitespace().collect();
         Move::from_dir(
@@ -68,26 +70,75 @@ impl Move {
         )
     }
 
-    /// Splits this Move into a series of horizontal and vertical single-step moves.
-    pub fn split(&self) -> Vec<Move> {
-        let mut steps: Vec<Move> = Vec::new();
-        
-        let mut dx = self.dx;
-        while dx != 0 {
-            steps.push(Move::of(sgn(dx), 0));
-            dx -= sgn(dx);
-        }
-        
-        let mut dy = self.dy;
-        while dy != 0 {
-            steps.push(Move::of(0, sgn(dy)));
-            dy -= sgn(dy);
+    /// Splits this Move into a series of horizontal and vertical single-step moves,
+    /// all horizontal steps first.
+    pub fn split(&self) -> Steps {
+        self.split_with(Split::AxisFirst)
+    }
+
+    /// Splits this Move into a series of single-step moves, as decided by 'strategy'.
+    pub fn split_with(&self, strategy: Split) -> Steps {
+        let target = Point::of(self.dx, self.dy);
+        Steps { strategy, target, pos: Point::of(0, 0), error: self.dx.abs() - self.dy.abs() }
+    }
+}
+
+/// How a Move is split into single-step moves.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum Split {
+    /// All horizontal steps, then all vertical steps.
+    AxisFirst,
+    /// The steps along Bresenham's line to the end of the move, diagonal where the
+    /// line changes both row and column, and spread out evenly.
+    Interleaved,
+    /// Diagonal steps while both dx and dy remain, then horizontal or vertical steps.
+    DiagonalFirst,
+}
+
+/// An iterator over the single-step moves of a Move, see 'Move::split_with'.
+#[derive(Debug, Clone)]
+pub struct Steps {
+    strategy: Split,
+    target: Point,
+    pos: Point,
+    /// The error term of Bresenham's algorithm, for 'Split::Interleaved'.
+    error: i32,
+}
+
+impl Iterator for Steps {
+    type Item = Move;
+
+    fn next(&mut self) -> Option<Move> {
+        let (rx, ry) = (self.target.x - self.pos.x, self.target.y - self.pos.y);
+        if rx == 0 && ry == 0 {
+            return None;
         }
-        
-        steps
+        let step = match self.strategy {
+            Split::AxisFirst if rx != 0 => Move::of(sgn(rx), 0),
+            Split::AxisFirst => Move::of(0, sgn(ry)),
+            Split::DiagonalFirst => Move::of(sgn(rx), sgn(ry)),
+            Split::Interleaved => {
+                let (dx, dy) = (self.target.x.abs(), -self.target.y.abs());
+                let (step_x, step_y) = bresenham_step(&mut self.error, dx, dy);
+                Move::of(if step_x { sgn(rx) } else { 0 }, if step_y { sgn(ry) } else { 0 })
+            }
+        };
+        self.pos = self.pos.translate_by_move(&step);
+        Some(step)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (rx, ry) = ((self.target.x - self.pos.x).unsigned_abs(), (self.target.y - self.pos.y).unsigned_abs());
+        let len = match self.strategy {
+            Split::AxisFirst => rx + ry,
+            Split::Interleaved | Split::DiagonalFirst => rx.max(ry),
+        } as usize;
+        (len, Some(len))
     }
 }
 
+impl ExactSizeIterator for Steps {}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 pub struct Point {
     pub x: i32,
@@ -119,11 +170,250 @@ impl Point {
         self.x >= other.x - 1 && self.x <= other.x + 1 &&
             self.y >= other.y - 1 && self.y <= other.y + 1
     }
+
+    /// Returns the points on the line from this point to the other point, including
+    /// both, as drawn by Bresenham's algorithm. Each point is one step further along
+    /// the major axis than the one before it.
+    pub fn line_to(&self, other: &Point) -> Vec<Point> {
+        let dx = (other.x - self.x).abs();
+        let dy = -(other.y - self.y).abs();
+        let (sx, sy) = (sgn(other.x - self.x), sgn(other.y - self.y));
+
+        let mut line = Vec::new();
+        let mut error = dx + dy;
+        let mut pos = *self;
+        loop {
+            line.push(pos);
+            if pos == *other {
+                break;
+            }
+            let (step_x, step_y) = bresenham_step(&mut error, dx, dy);
+            if step_x {
+                pos.x += sx;
+            }
+            if step_y {
+                pos.y += sy;
+            }
+        }
+        line
+    }
+}
+
+/// Takes one step of Bresenham's algorithm on a line 'dx' wide and '-dy' high, with
+/// 'dx' >= 0 and 'dy' <= 0, updating 'error', and returns whether x and y change.
+fn bresenham_step(error: &mut i32, dx: i32, dy: i32) -> (bool, bool) {
+    let e2 = 2 * *error;
+    let step_x = e2 >= dy;
+    let step_y = e2 <= dx;
+    if step_x {
+        *error += dy;
+    }
+    if step_y {
+        *error += dx;
+    }
+    (step_x, step_y)
+}
+
+/// An axis-aligned rectangle of grid cells, from 'min' to 'max', both included.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    /// Creates a new rectangle with the opposite corners 'a' and 'b', in any order.
+    pub fn of(a: Point, b: Point) -> Self {
+        Rect {
+            min: Point::of(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::of(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub const fn width(&self) -> i64 {
+        (self.max.x - self.min.x) as i64 + 1
+    }
+
+    pub const fn height(&self) -> i64 {
+        (self.max.y - self.min.y) as i64 + 1
+    }
+
+    /// Returns the number of cells in this rectangle.
+    pub const fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    pub const fn contains(&self, pos: &Point) -> bool {
+        self.min.x <= pos.x && pos.x <= self.max.x && self.min.y <= pos.y && pos.y <= self.max.y
+    }
+
+    /// Returns the cells that are in both rectangles, or None if there are none.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Point::of(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point::of(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        if min.x <= max.x && min.y <= max.y { Some(Rect { min, max }) } else { None }
+    }
+
+    /// Returns all cells in this rectangle, column by column.
+    pub fn cells(&self) -> impl Iterator<Item = Point> + '_ {
+        (self.min.x..=self.max.x).flat_map(move |x| (self.min.y..=self.max.y).map(move |y| Point::of(x, y)))
+    }
+}
+
+/// Returns the number of cells on a line that are covered by at least 'k' of the
+/// half-open 'intervals'.
+fn count_covered_on_line(intervals: &[(i64, i64)], k: usize) -> i64 {
+    let mut events: Vec<(i64, i32)> = intervals.iter()
+        .flat_map(|(from, to)| [(*from, 1), (*to, -1)])
+        .collect();
+    events.sort();
+
+    let mut covered = 0;
+    let mut depth = 0;
+    let mut previous = 0;
+    for (pos, change) in events {
+        if depth >= k as i32 {
+            covered += pos - previous;
+        }
+        depth += change;
+        previous = pos;
+    }
+    covered
+}
+
+/// Returns the number of cells covered by at least 'k' of 'rects', sweeping a line over
+/// the x coordinates where rectangles start and end.
+pub fn count_covered_at_least(rects: &[Rect], k: usize) -> i64 {
+    let mut xs: Vec<i64> = rects.iter()
+        .flat_map(|r| [r.min.x as i64, r.max.x as i64 + 1])
+        .collect();
+    xs.sort();
+    xs.dedup();
+
+    xs.windows(2)
+        .map(|slab| {
+            let intervals: Vec<(i64, i64)> = rects.iter()
+                .filter(|r| r.min.x as i64 <= slab[0] && slab[0] <= r.max.x as i64)
+                .map(|r| (r.min.y as i64, r.max.y as i64 + 1))
+                .collect();
+            (slab[1] - slab[0]) * count_covered_on_line(&intervals, k)
+        })
+        .sum()
+}
+
+/// Returns the number of cells covered by the union of 'rects'.
+pub fn covered_area(rects: &[Rect]) -> i64 {
+    count_covered_at_least(rects, 1)
+}
+
+/// Returns the cells in 'bounds' that are not covered by any of 'rects', column by column.
+pub fn uncovered_cells(rects: &[Rect], bounds: &Rect) -> Vec<Point> {
+    let mut cells = Vec::new();
+    for x in bounds.min.x..=bounds.max.x {
+        let mut intervals: Vec<(i32, i32)> = rects.iter()
+            .filter(|r| r.min.x <= x && x <= r.max.x)
+            .map(|r| (r.min.y, r.max.y))
+            .collect();
+        intervals.sort();
+
+        let mut y = bounds.min.y;
+        for (from, to) in intervals {
+            while y < from && y <= bounds.max.y {
+                cells.push(Point::of(x, y));
+                y += 1;
+            }
+            y = y.max(to.saturating_add(1));
+        }
+        while y <= bounds.max.y {
+            cells.push(Point::of(x, y));
+            y += 1;
+        }
+    }
+    cells
+}
+
+/// Returns the edges of the closed 'polygon', from each vertex to the next.
+fn edges(polygon: &[Point]) -> impl Iterator<Item = (&Point, &Point)> {
+    polygon.iter().zip(polygon.iter().cycle().skip(1))
+}
+
+/// Returns twice the signed area of 'polygon' using the shoelace formula, so that it
+/// is a whole number. The area is positive if the vertices go counterclockwise, with
+/// y pointing up, and negative if they go clockwise.
+pub fn double_signed_area(polygon: &[Point]) -> i64 {
+    edges(polygon)
+        .map(|(a, b)| a.x as i64 * b.y as i64 - b.x as i64 * a.y as i64)
+        .sum()
+}
+
+/// Returns the area of 'polygon'.
+pub fn area(polygon: &[Point]) -> f64 {
+    double_signed_area(polygon).abs() as f64 / 2.0
+}
+
+/// Returns the length of the boundary of 'polygon'.
+pub fn perimeter(polygon: &[Point]) -> f64 {
+    edges(polygon)
+        .map(|(a, b)| ((b.x - a.x) as f64).hypot((b.y - a.y) as f64))
+        .sum()
+}
+
+/// Returns the number of lattice points on the boundary of 'polygon'.
+pub fn boundary_points(polygon: &[Point]) -> i64 {
+    edges(polygon)
+        .map(|(a, b)| gcd((b.x - a.x) as i64, (b.y - a.y) as i64))
+        .sum()
+}
+
+/// Returns the number of lattice points strictly inside the simple 'polygon', using
+/// Pick's theorem: A = I + B / 2 - 1.
+pub fn interior_points(polygon: &[Point]) -> i64 {
+    (double_signed_area(polygon).abs() - boundary_points(polygon) + 2) / 2
+}
+
+/// Where a point is relative to a polygon.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Returns whether 'point' is inside, on the boundary of, or outside the simple 'polygon'.
+pub fn locate(polygon: &[Point], point: &Point) -> Location {
+    let mut inside = false;
+    for (a, b) in edges(polygon) {
+        // The cross product is zero if the point is on the line through the edge
+        let cross = (b.x - a.x) as i64 * (point.y - a.y) as i64 - (point.x - a.x) as i64 * (b.y - a.y) as i64;
+        if cross == 0 &&
+            a.x.min(b.x) <= point.x && point.x <= a.x.max(b.x) &&
+            a.y.min(b.y) <= point.y && point.y <= a.y.max(b.y) {
+            return Location::Boundary;
+        }
+        // Count the edges that cross a ray from the point to the right
+        if (a.y > point.y) != (b.y > point.y) && (cross > 0) == (b.y > a.y) {
+            inside = !inside;
+        }
+    }
+    if inside { Location::Inside } else { Location::Outside }
+}
+
+/// Returns the vertices of the path that starts in 'start' and follows 'moves', one
+/// vertex after each move. If the path ends in 'start', it is a closed polygon.
+pub fn polygon_from_moves(start: Point, moves: &[Move]) -> Vec<Point> {
+    moves.iter()
+        .scan(start, |pos, mv| {
+            *pos = pos.translate_by_move(mv);
+            Some(*pos)
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
+    use crate::property::{check, no_shrink, shrink_vec};
+    use crate::random::Random;
     use super::*;
 
     const MV_1_0: Move = Move::of(1, 0);
@@ -166,39 +456,208 @@ mod tests {
 
     #[test]
     fn test_can_split_move() {
-        let steps = Move::of(0, 0).split();
+        let steps: Vec<Move> = Move::of(0, 0).split().collect();
         assert_eq!(steps, vec![]);
 
-        let steps = Move::of(1, 0).split();
+        let steps: Vec<Move> = Move::of(1, 0).split().collect();
         assert_eq!(steps, vec![MV_1_0]);
 
-        let steps = Move::of(0, -1).split();
+        let steps: Vec<Move> = Move::of(0, -1).split().collect();
         assert_eq!(steps, vec![MV_0_N1]);
 
-        let steps = Move::of(2, -1).split();
+        let steps: Vec<Move> = Move::of(2, -1).split().collect();
         assert_eq!(steps, vec![MV_1_0, MV_1_0, MV_0_N1]);
 
-        let steps = Move::of(2, 3).split();
+        let steps: Vec<Move> = Move::of(2, 3).split().collect();
         assert_eq!(steps, vec![MV_1_0, MV_1_0, MV_0_1, MV_0_1, MV_0_1]);
     }
 
+    #[test]
+    fn test_can_split_move_diagonal_first() {
+        let steps: Vec<Move> = Move::of(2, -3).split_with(Split::DiagonalFirst).collect();
+        assert_eq!(steps, vec![Move::of(1, -1), Move::of(1, -1), MV_0_N1]);
+        let steps: Vec<Move> = Move::of(-4, 0).split_with(Split::DiagonalFirst).collect();
+        assert_eq!(steps, vec![Move::of(-1, 0); 4]);
+    }
+
+    #[test]
+    fn test_can_split_move_interleaved() {
+        let steps: Vec<Move> = Move::of(4, 2).split_with(Split::Interleaved).collect();
+        assert_eq!(steps, vec![Move::of(1, 1), MV_1_0, Move::of(1, 1), MV_1_0]);
+        let steps: Vec<Move> = Move::of(0, 3).split_with(Split::Interleaved).collect();
+        assert_eq!(steps, vec![MV_0_1; 3]);
+    }
+
+    #[test]
+    fn test_split_moves_end_at_move() {
+        for mv in [Move::of(0, 0), Move::of(7, -3), Move::of(-2, 9), Move::of(-5, -5), Move::of(1, 0)] {
+            for strategy in [Split::AxisFirst, Split::Interleaved, Split::DiagonalFirst] {
+                let steps = mv.split_with(strategy);
+                let len = steps.len();
+                let steps: Vec<Move> = steps.collect();
+                assert_eq!(steps.len(), len);
+                assert!(steps.iter().all(|s| s.dx.abs() <= 1 && s.dy.abs() <= 1 && *s != Move::of(0, 0)));
+                let end = steps.iter().fold(Point::of(0, 0), |p, s| p.translate_by_move(s));
+                assert_eq!(end, Point::of(mv.dx, mv.dy), "{:?} {:?}", mv, strategy);
+            }
+        }
+        // Interleaved steps visit the same points as Bresenham's line
+        let line: Vec<Point> = Move::of(7, -3).split_with(Split::Interleaved)
+            .scan(Point::of(0, 0), |p, s| { *p = p.translate_by_move(&s); Some(*p) })
+            .collect();
+        assert_eq!(line, Point::of(0, 0).line_to(&Point::of(7, -3))[1..]);
+    }
+
+    #[test]
+    fn test_can_draw_line() {
+        assert_eq!(Point::of(2, 3).line_to(&Point::of(2, 3)), vec![Point::of(2, 3)]);
+        assert_eq!(Point::of(0, 0).line_to(&Point::of(3, 0)),
+                   vec![Point::of(0, 0), Point::of(1, 0), Point::of(2, 0), Point::of(3, 0)]);
+        assert_eq!(Point::of(0, 0).line_to(&Point::of(4, 2)),
+                   vec![Point::of(0, 0), Point::of(1, 1), Point::of(2, 1), Point::of(3, 2), Point::of(4, 2)]);
+        assert_eq!(Point::of(1, 1).line_to(&Point::of(-1, -3)),
+                   vec![Point::of(1, 1), Point::of(0, 0), Point::of(0, -1), Point::of(-1, -2), Point::of(-1, -3)]);
+    }
+
+    fn square() -> Vec<Point> {
+        vec![Point::of(0, 0), Point::of(4, 0), Point::of(4, 4), Point::of(0, 4)]
+    }
+
+    #[test]
+    fn test_can_calculate_area() {
+        assert_eq!(double_signed_area(&square()), 32);
+        let clockwise: Vec<Point> = square().into_iter().rev().collect();
+        assert_eq!(double_signed_area(&clockwise), -32);
+        assert_eq!(area(&clockwise), 16.0);
+        let triangle = vec![Point::of(0, 0), Point::of(3, 0), Point::of(0, 1)];
+        assert_eq!(area(&triangle), 1.5);
+        assert_eq!(area(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_can_calculate_perimeter() {
+        assert_eq!(perimeter(&square()), 16.0);
+        let triangle = vec![Point::of(0, 0), Point::of(3, 0), Point::of(0, 4)];
+        assert_eq!(perimeter(&triangle), 12.0);
+    }
+
+    #[test]
+    fn test_can_count_lattice_points() {
+        assert_eq!(boundary_points(&square()), 16);
+        assert_eq!(interior_points(&square()), 9);
+        let triangle = vec![Point::of(0, 0), Point::of(4, 0), Point::of(0, 4)];
+        assert_eq!(boundary_points(&triangle), 12);
+        assert_eq!(interior_points(&triangle), 3);
+    }
+
+    #[test]
+    fn test_can_locate_point() {
+        assert_eq!(locate(&square(), &Point::of(2, 2)), Location::Inside);
+        assert_eq!(locate(&square(), &Point::of(1, 3)), Location::Inside);
+        assert_eq!(locate(&square(), &Point::of(4, 2)), Location::Boundary);
+        assert_eq!(locate(&square(), &Point::of(0, 0)), Location::Boundary);
+        assert_eq!(locate(&square(), &Point::of(5, 2)), Location::Outside);
+        assert_eq!(locate(&square(), &Point::of(-1, 0)), Location::Outside);
+        assert_eq!(locate(&square(), &Point::of(2, 4)), Location::Boundary);
+
+        // Pick's theorem agrees with counting the points one by one
+        let polygon = vec![Point::of(0, 0), Point::of(6, 0), Point::of(6, 5), Point::of(3, 2), Point::of(0, 5)];
+        let mut inside = 0;
+        for x in -1..=7 {
+            for y in -1..=6 {
+                if locate(&polygon, &Point::of(x, y)) == Location::Inside {
+                    inside += 1;
+                }
+            }
+        }
+        assert_eq!(inside, interior_points(&polygon));
+    }
+
+    #[test]
+    fn test_can_dig_lagoon() {
+        let plan = ["R 6", "D 5", "L 2", "D 2", "R 2", "D 2", "L 5", "U 2", "L 1", "U 2", "R 2", "U 3", "L 2", "U 2"];
+        let moves: Vec<Move> = plan.iter().map(|s| Move::from_str(s)).collect();
+        let polygon = polygon_from_moves(Point::of(0, 0), &moves);
+        assert_eq!(polygon.len(), 14);
+        assert_eq!(polygon.last(), Some(&Point::of(0, 0)));
+        assert_eq!(interior_points(&polygon) + boundary_points(&polygon), 62);
+    }
+
+    #[test]
+    fn test_can_intersect_rects() {
+        let a = Rect::of(Point::of(0, 0), Point::of(4, 2));
+        let b = Rect::of(Point::of(6, 5), Point::of(3, 1));
+        assert_eq!(b, Rect { min: Point::of(3, 1), max: Point::of(6, 5) });
+        assert_eq!(a.intersection(&b), Some(Rect::of(Point::of(3, 1), Point::of(4, 2))));
+        assert_eq!(a.intersection(&Rect::of(Point::of(5, 0), Point::of(5, 0))), None);
+        assert_eq!(a.area(), 15);
+        assert_eq!(a.cells().count(), 15);
+        assert!(a.contains(&Point::of(4, 2)));
+        assert!(!a.contains(&Point::of(4, 3)));
+    }
+
+    #[test]
+    fn test_can_sweep_rects() {
+        let rects = vec![
+            Rect::of(Point::of(0, 0), Point::of(3, 3)),
+            Rect::of(Point::of(2, 2), Point::of(5, 5)),
+            Rect::of(Point::of(3, 0), Point::of(3, 5)),
+        ];
+        // The column at x = 3 is inside the union of the squares
+        assert_eq!(covered_area(&rects), 16 + 16 - 4);
+        assert_eq!(count_covered_at_least(&rects, 2), 4 + 4);
+        assert_eq!(count_covered_at_least(&rects, 3), 2);
+        assert_eq!(covered_area(&[]), 0);
+
+        let bounds = Rect::of(Point::of(0, 0), Point::of(5, 5));
+        let uncovered = uncovered_cells(&rects, &bounds);
+        assert_eq!(uncovered.len() as i64, bounds.area() - covered_area(&rects));
+        assert_eq!(uncovered[0], Point::of(0, 4));
+    }
+
+    fn random_rects(random: &mut Random, size: usize) -> Vec<Rect> {
+        (0..random.range(0, size as i64 / 5 + 1))
+            .map(|_| {
+                let corner = Point::of(random.range(-10, 10) as i32, random.range(-10, 10) as i32);
+                Rect::of(corner, corner.translate(random.range(0, 8) as i32, random.range(0, 8) as i32))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn property_sweep_agrees_with_filling_cells() {
+        check(random_rects, |rects| shrink_vec(rects, no_shrink), |rects| {
+            let mut counts: HashMap<Point, usize> = HashMap::new();
+            for cell in rects.iter().flat_map(Rect::cells) {
+                *counts.entry(cell).or_insert(0) += 1;
+            }
+            let covered: HashSet<Point> = counts.keys().copied().collect();
+            let bounds = Rect::of(Point::of(-12, -12), Point::of(20, 20));
+            let uncovered: HashSet<Point> = uncovered_cells(rects, &bounds).into_iter().collect();
+
+            (1..=3).all(|k| count_covered_at_least(rects, k) == counts.values().filter(|c| **c >= k).count() as i64) &&
+                bounds.cells().all(|cell| covered.contains(&cell) != uncovered.contains(&cell)) &&
+                uncovered.len() == uncovered_cells(rects, &bounds).len()
+        });
+    }
+
     #[test]
     fn test_point_touches() {
-        assert_eq!(Point::of(5, 8).touches(&Point::of(4, 8)), true);
-        assert_eq!(Point::of(5, 8).touches(&Point::of(6, 8)), true);
-        assert_eq!(Point::of(5, 8).touches(&Point::of(5, 7)), true);
-        assert_eq!(Point::of(5, 8).touches(&Point::of(5, 9)), true);
+        assert!(Point::of(5, 8).touches(&Point::of(4, 8)));
+        assert!(Point::of(5, 8).touches(&Point::of(6, 8)));
+        assert!(Point::of(5, 8).touches(&Point::of(5, 7)));
+        assert!(Point::of(5, 8).touches(&Point::of(5, 9)));
 
-        assert_eq!(Point::of(-6, 0).touches(&Point::of(-5, -1)), true);
-        assert_eq!(Point::of(-6, 0).touches(&Point::of(-7, -1)), true);
-        assert_eq!(Point::of(-6, 0).touches(&Point::of(-5, 1)), true);
-        assert_eq!(Point::of(-6, 0).touches(&Point::of(-7, 1)), true);
+        assert!(Point::of(-6, 0).touches(&Point::of(-5, -1)));
+        assert!(Point::of(-6, 0).touches(&Point::of(-7, -1)));
+        assert!(Point::of(-6, 0).touches(&Point::of(-5, 1)));
+        assert!(Point::of(-6, 0).touches(&Point::of(-7, 1)));
 
-        assert_eq!(Point::of(17, 18).touches(&Point::of(17, 18)), true);
+        assert!(Point::of(17, 18).touches(&Point::of(17, 18)));
 
-        assert_eq!(Point::of(17, 18).touches(&Point::of(17, 20)), false);
-        assert_eq!(Point::of(17, 18).touches(&Point::of(15, 18)), false);
-        assert_eq!(Point::of(17, 18).touches(&Point::of(-17, 18)), false);
+        assert!(!Point::of(17, 18).touches(&Point::of(17, 20)));
+        assert!(!Point::of(17, 18).touches(&Point::of(15, 18)));
+        assert!(!Point::of(17, 18).touches(&Point::of(-17, 18)));
     }
 
     #[test]
@@ -0,0 +1,256 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::geom::{Point, Rect};
+
+/// Which cells are neighbours of a cell.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Connectivity {
+    /// The cells above, below, left and right.
+    Four,
+    /// The cells above, below, left and right, and the diagonal cells.
+    Eight,
+}
+
+const SIDES: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const DIAGONALS: [(i32, i32); 4] = [(1, 1), (-1, 1), (1, -1), (-1, -1)];
+
+impl Connectivity {
+    /// Returns the neighbours of 'pos'.
+    pub fn neighbours(&self, pos: &Point) -> Vec<Point> {
+        let steps: &[(i32, i32)] = match self {
+            Connectivity::Four => &SIDES,
+            Connectivity::Eight => &[SIDES, DIAGONALS].concat(),
+        };
+        steps.iter().map(|(dx, dy)| pos.translate(*dx, *dy)).collect()
+    }
+}
+
+/// A set of cells, either a dense grid where the cell at (x, y) is in row y and column x,
+/// or a sparse set of points.
+pub trait Grid {
+    /// Returns true if 'pos' is a cell of this grid.
+    fn contains(&self, pos: &Point) -> bool;
+
+    /// Returns all cells of this grid, row by row.
+    fn cells(&self) -> Vec<Point>;
+}
+
+impl<T> Grid for Vec<Vec<T>> {
+    fn contains(&self, pos: &Point) -> bool {
+        pos.y >= 0 && pos.x >= 0 && (pos.y as usize) < self.len() && (pos.x as usize) < self[pos.y as usize].len()
+    }
+
+    fn cells(&self) -> Vec<Point> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(y, row)| (0..row.len()).map(move |x| Point::of(x as i32, y as i32)))
+            .collect()
+    }
+}
+
+impl Grid for HashSet<Point> {
+    fn contains(&self, pos: &Point) -> bool {
+        HashSet::contains(self, pos)
+    }
+
+    fn cells(&self) -> Vec<Point> {
+        let mut cells: Vec<Point> = self.iter().copied().collect();
+        cells.sort_by_key(|p| (p.y, p.x));
+        cells
+    }
+}
+
+/// Returns all cells of 'grid' that can be reached from 'start' by moving between
+/// neighbouring cells that are 'passable'. Returns no cells if 'start' is not passable.
+pub fn flood_fill(
+    grid: &impl Grid,
+    start: Point,
+    connectivity: Connectivity,
+    passable: impl Fn(&Point) -> bool,
+) -> HashSet<Point> {
+    let mut reached: HashSet<Point> = HashSet::new();
+    if !grid.contains(&start) || !passable(&start) {
+        return reached;
+    }
+    reached.insert(start);
+    let mut queue = VecDeque::from([start]);
+    while let Some(pos) = queue.pop_front() {
+        for next in connectivity.neighbours(&pos) {
+            if grid.contains(&next) && !reached.contains(&next) && passable(&next) {
+                reached.insert(next);
+                queue.push_back(next);
+            }
+        }
+    }
+    reached
+}
+
+/// The connected components of a grid, numbered from 0 in the order their first cell
+/// appears, row by row.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Components {
+    /// The component of each cell.
+    pub labels: HashMap<Point, usize>,
+    /// The number of cells in each component.
+    pub sizes: Vec<usize>,
+    /// The smallest rectangle around each component.
+    pub bounds: Vec<Rect>,
+}
+
+impl Components {
+    /// Returns the number of components.
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty()
+    }
+
+    /// Returns the cells of component 'label'.
+    pub fn get_region(&self, label: usize) -> HashSet<Point> {
+        self.labels.iter()
+            .filter(|(_, l)| **l == label)
+            .map(|(pos, _)| *pos)
+            .collect()
+    }
+
+    /// Returns the labels as a dense grid of 'width' by 'height' cells, with None for
+    /// the cells that are not in any component.
+    pub fn to_grid(&self, width: usize, height: usize) -> Vec<Vec<Option<usize>>> {
+        (0..height)
+            .map(|y| (0..width).map(|x| self.labels.get(&Point::of(x as i32, y as i32)).copied()).collect())
+            .collect()
+    }
+}
+
+/// Labels the connected components of 'grid', where two neighbouring cells are in the
+/// same component if 'joined' returns true for them, e.g. if they have the same value.
+pub fn label_components(
+    grid: &impl Grid,
+    connectivity: Connectivity,
+    joined: impl Fn(&Point, &Point) -> bool,
+) -> Components {
+    let mut components = Components { labels: HashMap::new(), sizes: Vec::new(), bounds: Vec::new() };
+    for start in grid.cells() {
+        if components.labels.contains_key(&start) {
+            continue;
+        }
+        let label = components.len();
+        let mut size = 0;
+        let mut bounds = Rect::of(start, start);
+        components.labels.insert(start, label);
+        let mut queue = VecDeque::from([start]);
+        while let Some(pos) = queue.pop_front() {
+            size += 1;
+            bounds = Rect::of(
+                Point::of(bounds.min.x.min(pos.x), bounds.min.y.min(pos.y)),
+                Point::of(bounds.max.x.max(pos.x), bounds.max.y.max(pos.y)),
+            );
+            for next in connectivity.neighbours(&pos) {
+                if grid.contains(&next) && !components.labels.contains_key(&next) && joined(&pos, &next) {
+                    components.labels.insert(next, label);
+                    queue.push_back(next);
+                }
+            }
+        }
+        components.sizes.push(size);
+        components.bounds.push(bounds);
+    }
+    components
+}
+
+/// Returns the length of the fence around 'region': the number of sides of its cells
+/// that face a cell outside the region.
+pub fn region_perimeter(region: &HashSet<Point>) -> usize {
+    region.iter()
+        .flat_map(|pos| Connectivity::Four.neighbours(pos))
+        .filter(|next| !region.contains(next))
+        .count()
+}
+
+/// Returns the number of straight sides of the fence around 'region', including the
+/// fences around holes. A polygon has as many sides as corners, so count the corners.
+pub fn region_sides(region: &HashSet<Point>) -> usize {
+    region.iter()
+        .map(|pos| {
+            DIAGONALS.iter()
+                .filter(|(dx, dy)| {
+                    let horizontal = region.contains(&pos.translate(*dx, 0));
+                    let vertical = region.contains(&pos.translate(0, *dy));
+                    let diagonal = region.contains(&pos.translate(*dx, *dy));
+                    // An outside corner, or an inside corner
+                    (!horizontal && !vertical) || (horizontal && vertical && !diagonal)
+                })
+                .count()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_grid(rows: &[&str]) -> Vec<Vec<char>> {
+        rows.iter().map(|row| row.chars().collect()).collect()
+    }
+
+    fn to_set(rows: &[&str]) -> HashSet<Point> {
+        let grid = to_grid(rows);
+        grid.cells().into_iter().filter(|p| grid[p.y as usize][p.x as usize] == '#').collect()
+    }
+
+    #[test]
+    fn test_can_flood_fill() {
+        let grid = to_grid(&["..#..", "..#..", "###..", "....."]);
+        let open = |p: &Point| grid[p.y as usize][p.x as usize] == '.';
+        assert_eq!(flood_fill(&grid, Point::of(0, 0), Connectivity::Four, open).len(), 4);
+        assert_eq!(flood_fill(&grid, Point::of(4, 0), Connectivity::Four, open).len(), 11);
+        assert_eq!(flood_fill(&grid, Point::of(2, 0), Connectivity::Four, open).len(), 0);
+        assert_eq!(flood_fill(&grid, Point::of(9, 9), Connectivity::Four, open).len(), 0);
+
+        // The wall has a diagonal gap
+        let grid = to_grid(&[".#", "#."]);
+        let open = |p: &Point| grid[p.y as usize][p.x as usize] == '.';
+        assert_eq!(flood_fill(&grid, Point::of(0, 0), Connectivity::Four, open).len(), 1);
+        assert_eq!(flood_fill(&grid, Point::of(0, 0), Connectivity::Eight, open).len(), 2);
+    }
+
+    #[test]
+    fn test_can_flood_fill_sparse_set() {
+        let cells = to_set(&["##...", ".#..#", "....#"]);
+        let filled = flood_fill(&cells, Point::of(0, 0), Connectivity::Four, |_| true);
+        assert_eq!(filled, HashSet::from([Point::of(0, 0), Point::of(1, 0), Point::of(1, 1)]));
+    }
+
+    #[test]
+    fn test_can_label_components() {
+        let garden = to_grid(&["AAAA", "BBCD", "BBCC", "EEEC"]);
+        let same = |a: &Point, b: &Point| garden[a.y as usize][a.x as usize] == garden[b.y as usize][b.x as usize];
+        let components = label_components(&garden, Connectivity::Four, same);
+        assert_eq!(components.len(), 5);
+        assert_eq!(components.sizes, vec![4, 4, 4, 1, 3]);
+        assert_eq!(components.bounds[2], Rect::of(Point::of(2, 1), Point::of(3, 3)));
+        assert_eq!(components.to_grid(4, 4)[1], vec![Some(1), Some(1), Some(2), Some(3)]);
+
+        let perimeters: Vec<usize> = (0..5).map(|l| region_perimeter(&components.get_region(l))).collect();
+        assert_eq!(perimeters, vec![10, 8, 10, 4, 8]);
+        let sides: Vec<usize> = (0..5).map(|l| region_sides(&components.get_region(l))).collect();
+        assert_eq!(sides, vec![4, 4, 8, 4, 4]);
+    }
+
+    #[test]
+    fn test_can_count_sides_with_holes() {
+        let region = to_set(&["#####", "#.#.#", "#####"]);
+        assert_eq!(region_perimeter(&region), 16 + 4 + 4);
+        assert_eq!(region_sides(&region), 4 + 4 + 4);
+    }
+
+    #[test]
+    fn test_can_count_islands() {
+        let islands = to_set(&["#..#", ".#..", "...#", "##.#"]);
+        assert_eq!(label_components(&islands, Connectivity::Four, |_, _| true).len(), 5);
+        let components = label_components(&islands, Connectivity::Eight, |_, _| true);
+        assert_eq!(components.sizes, vec![2, 1, 2, 2]);
+        assert_eq!(components.to_grid(4, 4)[0], vec![Some(0), None, None, Some(1)]);
+    }
+}
@@ -0,0 +1,213 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+
+/// Environment variable that, if set, names a directory to read input files from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The user whose puzzle inputs are stored directly in the input directory.
+pub const DEFAULT_USER: &str = "default";
+
+/// The directory the input files are read from if nothing else is specified.
+const DEFAULT_INPUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input");
+
+/// An input file, optionally embedded in the binary at compile time.
+#[derive(Debug)]
+pub struct InputFile {
+    pub name: &'static str,
+    embedded: Option<&'static str>,
+}
+
+impl InputFile {
+    /// Returns the contents of this input file. A file in AOC_INPUT_DIR takes precedence
+    /// over the embedded contents, which in turn take precedence over the file in the
+    /// crate's input directory.
+    pub fn read(&self) -> String {
+        if env::var(INPUT_DIR_VAR).is_err() {
+            if let Some(contents) = self.embedded {
+                return String::from(contents);
+            }
+        }
+        read_file(&self.get_path(DEFAULT_USER))
+    }
+
+    /// Returns the contents of this input file for 'user', or None if that user has
+    /// no such file. The inputs of other users than the default user are never embedded.
+    pub fn read_for_user(&self, user: &str) -> Option<String> {
+        if user == DEFAULT_USER {
+            return Some(self.read());
+        }
+        fs::read_to_string(self.get_path(user)).ok()
+    }
+
+    /// Returns the path to this input file for 'user'. The inputs of the default user are
+    /// stored directly in the input directory, and the inputs of other users are stored
+    /// in a subdirectory named after the user.
+    pub fn get_path(&self, user: &str) -> String {
+        let dir = env::var(INPUT_DIR_VAR).unwrap_or_else(|_| String::from(DEFAULT_INPUT_DIR));
+        if user == DEFAULT_USER {
+            format!("{}/{}", dir, self.name)
+        } else {
+            format!("{}/{}/{}", dir, user, self.name)
+        }
+    }
+
+    /// Returns true if the contents of this input file are embedded in the binary.
+    pub const fn is_embedded(&self) -> bool {
+        self.embedded.is_some()
+    }
+}
+
+/// The puzzle input and the worked example for a single day.
+#[derive(Debug)]
+pub struct DayInputs {
+    pub day: u32,
+    pub puzzle: InputFile,
+    pub example: InputFile,
+}
+
+#[cfg(feature = "embed-inputs")]
+macro_rules! input_file {
+    ($name:literal) => {
+        InputFile {
+            name: $name,
+            embedded: Some(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/input/", $name))),
+        }
+    };
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+macro_rules! input_file {
+    ($name:literal) => {
+        InputFile { name: $name, embedded: None }
+    };
+}
+
+macro_rules! day_inputs {
+    ($day:literal, $puzzle:literal, $example:literal) => {
+        DayInputs { day: $day, puzzle: input_file!($puzzle), example: input_file!($example) }
+    };
+}
+
+/// All registered inputs, ordered by day.
+pub const INPUTS: &[DayInputs] = &[
+    day_inputs!(1, "day01.txt", "example01.txt"),
+    day_inputs!(2, "day02.txt", "example02.txt"),
+    day_inputs!(3, "day03.txt", "example03.txt"),
+    day_inputs!(4, "day04.txt", "example04.txt"),
+    day_inputs!(5, "day05.txt", "example05.txt"),
+    day_inputs!(6, "day06.txt", "example06.txt"),
+    day_inputs!(7, "day07.txt", "example07.txt"),
+    day_inputs!(8, "day08.txt", "example08.txt"),
+    day_inputs!(9, "day09.txt", "example09.txt"),
+];
+
+/// Returns the registered inputs for 'day'.
+pub fn get_inputs(day: u32) -> &'static DayInputs {
+    INPUTS.iter()
+        .find(|inputs| inputs.day == day)
+        .unwrap_or_else(|| panic!("No inputs registered for day {}", day))
+}
+
+/// Returns the puzzle input for 'day'.
+pub fn puzzle(day: u32) -> String {
+    get_inputs(day).puzzle.read()
+}
+
+/// Returns the worked example from the puzzle text for 'day'.
+pub fn example(day: u32) -> String {
+    get_inputs(day).example.read()
+}
+
+/// Where the input of a single run of a solution comes from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Source {
+    /// The registered puzzle input of the day.
+    Puzzle,
+    /// The registered worked example of the day.
+    Example,
+    /// Everything read from standard input, for example from a pipe.
+    Stdin,
+    /// The file at the given path.
+    File(String),
+    /// The given string, for example pasted from the puzzle text.
+    Text(String),
+}
+
+impl Source {
+    /// Returns the input for 'day' from this source.
+    pub fn read(&self, day: u32) -> String {
+        match self {
+            Source::Puzzle => puzzle(day),
+            Source::Example => example(day),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).expect("Failed to read standard input");
+                input
+            }
+            Source::File(path) => read_file(path),
+            Source::Text(text) => text.clone(),
+        }
+    }
+}
+
+/// Reads the input file at 'path'.
+pub fn read_file(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|_| panic!("File not found: {}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lines;
+    use super::*;
+
+    #[test]
+    fn test_all_days_are_registered_in_order() {
+        for pair in INPUTS.windows(2) {
+            assert!(pair[0].day < pair[1].day, "day {} before day {}", pair[0].day, pair[1].day);
+        }
+    }
+
+    #[test]
+    fn test_can_read_puzzle() {
+        let input = puzzle(7);
+        assert!(input.starts_with("$ cd /\n$ ls\n"));
+    }
+
+    #[test]
+    fn test_can_read_example() {
+        let result: Vec<String> = lines(example(8).as_bytes()).collect();
+        assert_eq!(result, vec!["30373", "25512", "65332", "33549", "35390"]);
+    }
+
+    #[test]
+    fn test_can_get_path() {
+        let inputs = get_inputs(3);
+        assert!(inputs.puzzle.get_path(DEFAULT_USER).ends_with("/input/day03.txt"));
+        assert!(inputs.puzzle.get_path("alice").ends_with("/input/alice/day03.txt"));
+    }
+
+    #[test]
+    fn test_cannot_read_missing_user() {
+        assert_eq!(get_inputs(3).puzzle.read_for_user("no-such-user"), None);
+    }
+
+    #[test]
+    fn test_is_embedded() {
+        assert_eq!(get_inputs(1).puzzle.is_embedded(), cfg!(feature = "embed-inputs"));
+    }
+
+    #[test]
+    fn test_can_read_from_source() {
+        assert_eq!(Source::Example.read(6), example(6));
+        assert_eq!(Source::Text(String::from("R 4\nU 4")).read(9), "R 4\nU 4");
+        let path = get_inputs(8).example.get_path(DEFAULT_USER);
+        assert_eq!(Source::File(path).read(1), example(8));
+    }
+
+    #[test]
+    #[should_panic(expected = "No inputs registered for day 26")]
+    fn test_cannot_read_unknown_day() {
+        puzzle(26);
+    }
+}
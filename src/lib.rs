@@ -1,93 +1,170 @@
+
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod bigint;
+pub mod cycle;
+pub mod days;
+pub mod disjoint_set;
+pub mod generate;
 pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod num;
+pub mod property;
+pub mod random;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
 
-use std::fs;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Lines};
+use crate::bigint::BigInt;
 
-pub fn read_vector_of_string(path: &str) -> Vec<String> {
-    fs::read_to_string(path)
-        .expect(&*format!("File not found: {}", path))
-        .split("\n")
+/// Opens the file at 'path' for buffered reading.
+pub fn open(path: &str) -> BufReader<File> {
+    BufReader::new(File::open(path).unwrap_or_else(|_| panic!("File not found: {}", path)))
+}
+
+/// Returns a lazy iterator over the trimmed, non-empty lines in 'reader'.
+pub fn lines<R: BufRead>(reader: R) -> impl Iterator<Item = String> {
+    reader.lines()
+        .map(|line| line.expect("Failed to read line"))
+        .map(|line| String::from(line.trim()))
+        .filter(|line| !line.is_empty())
+}
+
+/// Returns a lazy iterator over the records in 'reader', where each record
+/// is a non-empty line split into trimmed fields by 'separator'.
+pub fn records<'a, R: BufRead + 'a>(reader: R, separator: &'a str) -> impl Iterator<Item = Vec<String>> + 'a {
+    lines(reader).map(move |line| line
+        .split(separator)
         .map(str::trim)
         .map(String::from)
-        .filter(|s| !s.is_empty())
-        .collect()
+        .collect())
+}
+
+/// Returns a lazy iterator over the groups of lines in 'reader', where groups
+/// are separated by one or more blank lines. The last group is returned even
+/// if the input does not end with a blank line.
+pub fn groups<R: BufRead>(reader: R) -> Groups<R> {
+    Groups { lines: reader.lines() }
+}
+
+/// An iterator over groups of lines separated by blank lines, see 'groups'.
+pub struct Groups<R> {
+    lines: Lines<R>,
+}
+
+impl<R: BufRead> Iterator for Groups<R> {
+    type Item = Vec<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut group: Vec<String> = Vec::new();
+
+        for line in self.lines.by_ref() {
+            let line = line.expect("Failed to read line");
+            let line = line.trim();
+            if !line.is_empty() {
+                group.push(String::from(line));
+            } else if !group.is_empty() {
+                return Some(group);
+            }
+        }
+
+        if group.is_empty() { None } else { Some(group) }
+    }
+}
+
+pub fn read_vector_of_string(path: &str) -> Vec<String> {
+    lines(open(path)).collect()
 }
 
 pub fn read_vector_of_i32(path: &str) -> Vec<i32> {
-    read_vector_of_string(path).iter()
-        .map(|s| to_i32(s))
+    lines(open(path))
+        .map(|s| to_i32(&s))
         .collect()
 }
 
 pub fn read_matrix_of_string(path: &str, separator: &str) -> Vec<Vec<String>> {
-    read_vector_of_string(path).iter()
-        .map(|s| s
-            .split(separator)
-            .map(str::trim)
-            .map(String::from)
-            .collect::<Vec<String>>())
-        .collect()
+    records(open(path), separator).collect()
 }
 
 pub fn read_matrix_of_i32(path: &str, separator: &str) -> Vec<Vec<i32>> {
-    to_matrix_of_i32(&read_matrix_of_string(path, separator))
+    records(open(path), separator)
+        .map(|record| to_vector_of_i32(&record))
+        .collect()
 }
 
 /// Reads a file of grouped strings, separated by blank lines.
 pub fn read_blank_separated_matrix_of_string(path: &str) -> Vec<Vec<String>> {
-    let lines: Vec<String> = fs::read_to_string(path)
-        .expect(&*format!("File not found: {}", path))
-        .split("\n")
-        .map(str::trim)
-        .map(String::from)
-        .collect();
-
-    let mut matrix: Vec<Vec<String>> = Vec::new();
-    let mut group: Vec<String> = Vec::new();
-
-    for line in lines {
-        if line.is_empty() && !group.is_empty() {
-            matrix.push(group);
-            group = Vec::new();
-        } else {
-            group.push(line);
-        }
-    }
-
-    matrix
+    groups(open(path)).collect()
 }
 
 /// Reads a file of grouped integers, separated by blank lines.
 pub fn read_blank_separated_matrix_of_i32(path: &str) -> Vec<Vec<i32>> {
-    to_matrix_of_i32(&read_blank_separated_matrix_of_string(path))
+    groups(open(path))
+        .map(|group| to_vector_of_i32(&group))
+        .collect()
+}
+
+/// Reads a file of integers of any size, one per line.
+pub fn read_vector_of_bigint(path: &str) -> Vec<BigInt> {
+    lines(open(path))
+        .map(|s| s.parse().unwrap_or_else(|_| panic!("Not an integer: {}", s)))
+        .collect()
+}
+
+/// Reads a file of grouped integers of any size, separated by blank lines.
+pub fn read_blank_separated_matrix_of_bigint(path: &str) -> Vec<Vec<BigInt>> {
+    groups(open(path))
+        .map(|group| to_vector_of_bigint(&group))
+        .collect()
 }
 
 /// Sums each row in the matrix, producing a vector of sums.
-pub fn sum_rows(matrix: &Vec<Vec<i32>>) -> Vec<i32> {
+pub fn sum_rows(matrix: &[Vec<i32>]) -> Vec<i32> {
     matrix.iter()
         .map(|row| row.iter().sum())
         .collect()
 }
 
+/// Sums each row in the matrix like 'sum_rows', but returns an error naming the first
+/// row whose sum overflows, instead of wrapping around.
+pub fn checked_sum_rows(matrix: &[Vec<i32>]) -> Result<Vec<i32>, String> {
+    matrix.iter()
+        .enumerate()
+        .map(|(i, row)| row.iter()
+            .try_fold(0i32, |sum, n| sum.checked_add(*n))
+            .ok_or_else(|| format!("Sum of row {} overflows i32", i)))
+        .collect()
+}
+
 /// Converts a matrix of string to a matrix of type i32.
-pub fn to_matrix_of_i32(matrix: &Vec<Vec<String>>) -> Vec<Vec<i32>> {
-    matrix.iter().map(to_vector_of_i32).collect()
+pub fn to_matrix_of_i32(matrix: &[Vec<String>]) -> Vec<Vec<i32>> {
+    matrix.iter().map(|row| to_vector_of_i32(row)).collect()
 }
 
 /// Converts a vector of string to a vector of type i32.
-pub fn to_vector_of_i32(vec: &Vec<String>) -> Vec<i32> {
+pub fn to_vector_of_i32(vec: &[String]) -> Vec<i32> {
     vec.iter().map(|s| to_i32(s)).collect::<Vec<i32>>()
 }
 
 pub fn to_i32(s: &str) -> i32 {
-    s.trim().parse().expect(&*format!("Not an integer: {}", s))
+    s.trim().parse().unwrap_or_else(|_| panic!("Not an integer: {}", s))
 }
 
 pub fn to_i64(s: &str) -> i64 {
-    s.trim().parse().expect(&*format!("Not an integer: {}", s))
+    s.trim().parse().unwrap_or_else(|_| panic!("Not an integer: {}", s))
+}
+
+/// Converts a vector of string to a vector of integers of any size.
+pub fn to_vector_of_bigint(vec: &[String]) -> Vec<BigInt> {
+    vec.iter().map(|s| s.parse().unwrap_or_else(|_| panic!("Not an integer: {}", s))).collect()
 }
 
 /// Converts a vector of String to a vector of &str.
-pub fn to_vector_of_str(vec: &Vec<String>) -> Vec<&str> {
+pub fn to_vector_of_str(vec: &[String]) -> Vec<&str> {
     vec.iter().map(|s| &s[..]).collect()
 }
 
@@ -103,48 +180,110 @@ pub fn split_into_groups<T>(v: Vec<T>, size: usize) -> Vec<Vec<T>>
 mod tests {
     use super::*;
 
+    fn test_path(name: &str) -> String {
+        format!("{}/input/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
     #[test]
     fn test_can_read_vector_of_string() {
-        let result = read_vector_of_string("input/test01.txt");
+        let result = read_vector_of_string(&test_path("test01.txt"));
         assert_eq!(result, vec!["1", "2", "3"]);
     }
 
     #[test]
     fn test_can_read_vector_of_i32() {
-        let result = read_vector_of_i32("input/test01.txt");
+        let result = read_vector_of_i32(&test_path("test01.txt"));
         assert_eq!(result, vec![1, 2, 3]);
     }
 
     #[test]
     fn test_can_read_matrix_of_string() {
-        let result = read_matrix_of_string("input/test02.txt", ",");
+        let result = read_matrix_of_string(&test_path("test02.txt"), ",");
         assert_eq!(result, vec![vec!["1", "11", "111"], vec!["2", "22", "222"], vec!["3", "33", "333"]]);
     }
 
     #[test]
     fn test_can_read_matrix_of_i32() {
-        let result = read_matrix_of_i32("input/test02.txt", ",");
+        let result = read_matrix_of_i32(&test_path("test02.txt"), ",");
         assert_eq!(result, vec![vec![1, 11, 111], vec![2, 22, 222], vec![3, 33, 333]]);
     }
 
     #[test]
     fn test_can_read_separated_matrix_of_string() {
-        let result = read_blank_separated_matrix_of_string("input/test03.txt");
+        let result = read_blank_separated_matrix_of_string(&test_path("test03.txt"));
         assert_eq!(result, vec![vec!["1", "2"], vec!["3"], vec!["4", "5", "6"]]);
     }
 
     #[test]
     fn test_can_read_separated_matrix_of_i32() {
-        let result = read_blank_separated_matrix_of_i32("input/test03.txt");
+        let result = read_blank_separated_matrix_of_i32(&test_path("test03.txt"));
         assert_eq!(result, vec![vec![1, 2], vec![3], vec![4, 5, 6]]);
     }
 
+    #[test]
+    fn test_can_read_bigint() {
+        let result = read_vector_of_bigint(&test_path("test01.txt"));
+        assert_eq!(result, vec![BigInt::from(1), BigInt::from(2), BigInt::from(3)]);
+        let result = read_blank_separated_matrix_of_bigint(&test_path("test03.txt"));
+        assert_eq!(result[2], vec![BigInt::from(4), BigInt::from(5), BigInt::from(6)]);
+
+        let vec = vec![String::from("99999999999999999999"), String::from("-1")];
+        let sum: BigInt = to_vector_of_bigint(&vec).into_iter().sum();
+        assert_eq!(sum.to_string(), "99999999999999999998");
+    }
+
+    #[test]
+    #[should_panic(expected = "Not an integer: 1.5")]
+    fn test_to_vector_of_bigint_panics_on_invalid_input() {
+        to_vector_of_bigint(&[String::from("1"), String::from("1.5")]);
+    }
+
+    #[test]
+    fn test_can_stream_lines() {
+        let input = "  a \n\nb\n   \nc";
+        let result: Vec<String> = lines(input.as_bytes()).collect();
+        assert_eq!(result, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_can_stream_records() {
+        let input = "1, 2\n\n3,4\n";
+        let result: Vec<Vec<String>> = records(input.as_bytes(), ",").collect();
+        assert_eq!(result, vec![vec!["1", "2"], vec!["3", "4"]]);
+    }
+
+    #[test]
+    fn test_can_stream_groups() {
+        let input = "\n1\n2\n\n\n\n3\n\n4\n5\n6";
+        let result: Vec<Vec<String>> = groups(input.as_bytes()).collect();
+        assert_eq!(result, vec![vec!["1", "2"], vec!["3"], vec!["4", "5", "6"]]);
+
+        let result: Vec<Vec<String>> = groups("".as_bytes()).collect();
+        assert_eq!(result, Vec::<Vec<String>>::new());
+    }
+
+    #[test]
+    fn test_can_stream_groups_lazily() {
+        let mut groups = groups("1\n\n2\n".as_bytes());
+        assert_eq!(groups.next(), Some(vec![String::from("1")]));
+        assert_eq!(groups.next(), Some(vec![String::from("2")]));
+        assert_eq!(groups.next(), None);
+    }
+
     #[test]
     fn test_can_sum_rows() {
-        let result = sum_rows(&vec![vec![1, 2], vec![3], vec![0, 0, 4, 5, 6, 0, 0]]);
+        let result = sum_rows(&[vec![1, 2], vec![3], vec![0, 0, 4, 5, 6, 0, 0]]);
         assert_eq!(result, vec![3, 3, 15]);
     }
 
+    #[test]
+    fn test_can_sum_rows_checked() {
+        let result = checked_sum_rows(&[vec![1, 2], vec![3], vec![i32::MAX, -1, 1]]);
+        assert_eq!(result, Ok(vec![3, 3, i32::MAX]));
+        let result = checked_sum_rows(&[vec![1, 2], vec![i32::MAX, 1, -1], vec![i32::MIN, -1]]);
+        assert_eq!(result, Err(String::from("Sum of row 1 overflows i32")));
+    }
+
     #[test]
     fn test_can_convert_vector_of_string() {
         let vec = vec![String::from("foo"), String::from("bar")];
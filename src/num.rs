@@ -0,0 +1,300 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// The operations on primitive integers that the number theory functions need.
+pub trait Integer:
+    Copy + Ord + Debug
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+    + Div<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+integer!(i32, i64, i128, u64);
+
+fn abs<T: Integer>(a: T) -> T {
+    if a < T::ZERO { T::ZERO - a } else { a }
+}
+
+/// Returns the greatest common divisor of 'a' and 'b', which is never negative,
+/// and is 0 only if both are 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    abs(a)
+}
+
+/// Returns the greatest common divisor of all 'values', or 0 if there are none.
+pub fn gcd_all<T: Integer>(values: &[T]) -> T {
+    values.iter().fold(T::ZERO, |a, b| gcd(a, *b))
+}
+
+/// Returns (g, x, y) such that a * x + b * y = g, where g is the greatest common divisor
+/// of 'a' and 'b'. Only for signed types, as x or y is usually negative.
+pub fn extended_gcd<T: Integer + Neg<Output = T>>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < T::ZERO { (-old_r, -old_x, -old_y) } else { (old_r, old_x, old_y) }
+}
+
+/// Returns the least common multiple of 'a' and 'b', which is never negative,
+/// or None if it overflows.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    abs(a / gcd(a, b)).checked_mul(abs(b))
+}
+
+/// Returns the least common multiple of all 'values', or 1 if there are none,
+/// or None if it overflows.
+pub fn lcm_all<T: Integer>(values: &[T]) -> Option<T> {
+    values.iter().try_fold(T::ONE, |a, b| lcm(a, *b))
+}
+
+/// Returns 'a' modulo 'm', between 0 and 'm' - 1, also for negative 'a'.
+pub fn modulo<T: Integer>(a: T, m: T) -> T {
+    assert!(m > T::ZERO, "Modulus {:?} is not positive", m);
+    let r = a % m;
+    if r < T::ZERO { r + m } else { r }
+}
+
+/// Returns 'a' + 'b' modulo 'm', for 'a' and 'b' that are already reduced modulo 'm'.
+fn add_reduced<T: Integer>(a: T, b: T, m: T) -> T {
+    if a >= m - b { a - (m - b) } else { a + b }
+}
+
+/// Returns 'a' * 'b' modulo 'm' without overflowing, even if 'a' * 'b' would overflow.
+pub fn mod_mul<T: Integer>(a: T, b: T, m: T) -> T {
+    let (mut a, mut b) = (modulo(a, m), modulo(b, m));
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    // Multiply by doubling and adding, reducing after every step
+    let two = T::ONE + T::ONE;
+    let mut product = T::ZERO;
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            product = add_reduced(product, a, m);
+        }
+        a = add_reduced(a, a, m);
+        b = b / two;
+    }
+    product
+}
+
+/// Returns 'base' to the power of 'exponent' modulo 'm', by repeated squaring.
+pub fn mod_pow<T: Integer>(base: T, exponent: T, m: T) -> T {
+    assert!(exponent >= T::ZERO, "Exponent {:?} is negative", exponent);
+    let two = T::ONE + T::ONE;
+    let (mut base, mut exponent) = (modulo(base, m), exponent);
+    let mut result = modulo(T::ONE, m);
+    while exponent > T::ZERO {
+        if exponent % two == T::ONE {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exponent = exponent / two;
+    }
+    result
+}
+
+/// Returns the x between 0 and 'm' - 1 with 'a' * x = 1 modulo 'm', or None if 'a' and
+/// 'm' are not coprime. Works for unsigned types, by keeping the coefficients reduced.
+pub fn mod_inv<T: Integer>(a: T, m: T) -> Option<T> {
+    let (mut old_r, mut r) = (modulo(a, m), m);
+    let (mut old_x, mut x) = (modulo(T::ONE, m), T::ZERO);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, add_reduced(old_x, m - mod_mul(q, x, m), m));
+    }
+    if old_r == T::ONE { Some(modulo(old_x, m)) } else { None }
+}
+
+/// Solves the system of congruences x = r modulo m for each (r, m) in 'congruences' with
+/// the Chinese remainder theorem, and returns (x, l), where l is the least common multiple
+/// of the moduli and x is between 0 and l - 1. The moduli need not be coprime. Returns
+/// None if there is no solution, or if l overflows.
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut solution = (T::ZERO, T::ONE);
+    for (r, m) in congruences {
+        let (x, l) = solution;
+        let g = gcd(l, *m);
+
+        // Solve x + l * t = r modulo m, which needs g to divide r - x
+        let difference = add_reduced(modulo(*r, *m), *m - modulo(x, *m), *m) % *m;
+        if difference % g != T::ZERO {
+            return None;
+        }
+        let reduced = *m / g;
+        let lcm = (l / g).checked_mul(*m)?;
+        let t = mod_mul(difference / g, mod_inv(l / g, reduced)?, reduced);
+        solution = (x + l * t, lcm);
+    }
+    Some(solution)
+}
+
+/// Returns the largest integer whose square is at most 'n'.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    assert!(n >= T::ZERO, "Cannot take the square root of {:?}", n);
+    let two = T::ONE + T::ONE;
+    if n < two {
+        return n;
+    }
+
+    // Newton's method decreases towards the root from any start above it
+    let mut x = n / two + T::ONE;
+    loop {
+        let y = (x + n / x) / two;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_get_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(12i64, -18), 6);
+        assert_eq!(gcd(0u64, 7), 7);
+        assert_eq!(gcd(0i128, 0), 0);
+        assert_eq!(gcd_all(&[24u64, 36, 60]), 12);
+        assert_eq!(gcd_all::<i64>(&[]), 0);
+
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(-4i64, 6), Some(12));
+        assert_eq!(lcm(0u64, 6), Some(0));
+        assert_eq!(lcm_all(&[2i64, 3, 4, 5, 6]), Some(60));
+        assert_eq!(lcm_all::<u64>(&[]), Some(1));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        let m = i64::MAX as i128;
+        assert_eq!(lcm(m, m - 1), Some(m * (m - 1)));
+    }
+
+    #[test]
+    fn test_gcd_exhaustively() {
+        for a in -30i64..=30 {
+            for b in -30i64..=30 {
+                let expected = if a == 0 && b == 0 { 0 } else { (1..=30).filter(|d| a % d == 0 && b % d == 0).max().unwrap() };
+                assert_eq!(gcd(a, b), expected, "gcd({}, {})", a, b);
+                assert_eq!(gcd(a as i128, b as i128), expected as i128);
+
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(g, expected);
+                assert_eq!(a * x + b * y, g, "extended_gcd({}, {})", a, b);
+
+                if a != 0 && b != 0 {
+                    let l = lcm(a, b).unwrap();
+                    assert_eq!(l, (1..).find(|l| l % a == 0 && l % b == 0).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_modular_arithmetic_exhaustively() {
+        for m in 1u64..=20 {
+            for a in 0..3 * m {
+                assert_eq!(mod_mul(a, a + 7, m), a * (a + 7) % m);
+                let naive = (0..a % 10).fold(1 % m, |p, _| p * a % m);
+                assert_eq!(mod_pow(a, a % 10, m), naive, "{}^{} mod {}", a, a % 10, m);
+                let inverse = (0..m).find(|x| a * x % m == 1 % m && gcd(a, m) == 1);
+                assert_eq!(mod_inv(a, m), inverse, "inverse of {} mod {}", a, m);
+                assert_eq!(mod_inv(a as i64, m as i64), inverse.map(|x| x as i64));
+                assert_eq!(mod_inv(-(a as i64), m as i64), inverse.map(|x| (m - x) as i64 % m as i64));
+            }
+        }
+        assert_eq!(modulo(-7, 3), 2);
+        assert_eq!(mod_pow(-2i64, 3, 5), 2);
+    }
+
+    #[test]
+    fn test_mod_mul_does_not_overflow() {
+        let m = u64::MAX - 58;
+        for (a, b) in [(m - 1, m - 1), (u64::MAX, u64::MAX), (1 << 63, 3), (123456789012345, 987654321098765)] {
+            let expected = (a as u128 * b as u128 % m as u128) as u64;
+            assert_eq!(mod_mul(a, b, m), expected);
+        }
+        let m = i64::MAX;
+        assert_eq!(mod_mul(m - 1, m - 1, m), 1);
+        assert_eq!(mod_mul(-1, m - 1, m), 1);
+        assert_eq!(mod_mul(i128::MAX - 1, i128::MAX - 1, i128::MAX), 1);
+
+        // Fermat's little theorem for the prime 2^61 - 1
+        let p: u64 = (1 << 61) - 1;
+        assert_eq!(mod_pow(123456789, p - 1, p), 1);
+        assert_eq!(mod_mul(mod_inv(123456789, p).unwrap(), 123456789, p), 1);
+    }
+
+    #[test]
+    fn test_can_solve_congruences() {
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt::<u64>(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(-1i64, 4)]), Some((3, 4)));
+        // The moduli are not coprime
+        assert_eq!(crt(&[(2u64, 6), (8, 10)]), Some((8, 30)));
+        assert_eq!(crt(&[(1u64, 6), (2, 4)]), None);
+        assert_eq!(crt(&[(1u64, u64::MAX), (0, u64::MAX - 1)]), None);
+        let m = i64::MAX as i128;
+        assert_eq!(crt(&[(1, m), (0, m - 1)]), Some(((m - 1) * (m - 1), m * (m - 1))));
+
+        for m1 in 1u64..=12 {
+            for m2 in 1u64..=12 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let l = lcm(m1, m2).unwrap();
+                        let expected = (0..l).find(|x| x % m1 == r1 && x % m2 == r2).map(|x| (x, l));
+                        assert_eq!(crt(&[(r1, m1), (r2, m2)]), expected, "{} mod {}, {} mod {}", r1, m1, r2, m2);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_isqrt_exhaustively() {
+        for n in 0u64..=10_000 {
+            let root = isqrt(n);
+            assert!(root * root <= n && (root + 1) * (root + 1) > n, "isqrt({})", n);
+            assert_eq!(isqrt(n as i64), root as i64);
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt(i64::MAX), 3037000499);
+        assert_eq!(isqrt(i128::MAX), 13043817825332782212);
+    }
+}
@@ -0,0 +1,219 @@
+use std::env;
+use std::fmt::Debug;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use crate::random::Random;
+
+/// Environment variable that, if set, overrides the seed used to generate test cases.
+pub const SEED_VAR: &str = "AOC_PROPERTY_SEED";
+
+/// Environment variable that, if set, overrides the number of test cases per property.
+pub const CASES_VAR: &str = "AOC_PROPERTY_CASES";
+
+/// The maximum number of shrinking steps, in case a shrinker never stops shrinking.
+const MAX_SHRINK_STEPS: usize = 10_000;
+
+/// Configures how a property is checked.
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// The number of random test cases to check.
+    pub cases: usize,
+    /// The seed for the random number generator.
+    pub seed: u64,
+    /// The size of the last test case. Sizes grow linearly from 1 to 'max_size'.
+    pub max_size: usize,
+}
+
+impl Default for Config {
+    /// Returns the default configuration, with the seed and the number of cases
+    /// overridden by AOC_PROPERTY_SEED and AOC_PROPERTY_CASES if they are set.
+    fn default() -> Self {
+        let from_env = |name: &str| env::var(name).ok().and_then(|s| s.parse().ok());
+        Config {
+            cases: from_env(CASES_VAR).unwrap_or(200) as usize,
+            seed: from_env(SEED_VAR).unwrap_or(2022),
+            max_size: 50,
+        }
+    }
+}
+
+/// A counterexample to a property, shrunk as far as possible.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Counterexample<T> {
+    /// The shrunk test case.
+    pub value: T,
+    /// The original test case, before shrinking.
+    pub original: T,
+    /// The number of test cases checked before finding the original test case.
+    pub cases: usize,
+    /// The number of successful shrinking steps.
+    pub shrinks: usize,
+}
+
+/// Returns true if 'property' holds for 'value'. A property that panics does not hold.
+fn holds<T>(property: &impl Fn(&T) -> bool, value: &T) -> bool {
+    catch_unwind(AssertUnwindSafe(|| property(value))).unwrap_or(false)
+}
+
+/// Checks 'property' against random test cases produced by 'generate', and returns the
+/// first counterexample found, shrunk by repeatedly replacing it with the first of its
+/// 'shrink' candidates that still fails. Returns None if the property held in all cases.
+pub fn find_counterexample<T: Clone>(
+    config: Config,
+    generate: impl Fn(&mut Random, usize) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> bool,
+) -> Option<Counterexample<T>> {
+    let mut random = Random::new(config.seed);
+    for case in 0..config.cases {
+        let size = 1 + case * config.max_size / config.cases.max(1);
+        let original = generate(&mut random, size);
+        if holds(&property, &original) {
+            continue;
+        }
+
+        let mut value = original.clone();
+        let mut shrinks = 0;
+        while shrinks < MAX_SHRINK_STEPS {
+            match shrink(&value).into_iter().find(|candidate| !holds(&property, candidate)) {
+                Some(smaller) => {
+                    value = smaller;
+                    shrinks += 1;
+                }
+                None => break,
+            }
+        }
+        return Some(Counterexample { value, original, cases: case + 1, shrinks });
+    }
+    None
+}
+
+/// Checks 'property' using the default configuration, and panics with the shrunk
+/// counterexample if the property does not hold.
+pub fn check<T: Clone + Debug>(
+    generate: impl Fn(&mut Random, usize) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> bool,
+) {
+    let config = Config::default();
+    if let Some(c) = find_counterexample(config, generate, shrink, property) {
+        panic!(
+            "Property failed after {} cases with seed {}, shrunk in {} steps from {:?} to {:?}",
+            c.cases, config.seed, c.shrinks, c.original, c.value,
+        );
+    }
+}
+
+/// Returns no candidates, for values that cannot be shrunk.
+pub fn no_shrink<T>(_: &T) -> Vec<T> {
+    Vec::new()
+}
+
+/// Returns candidates that are closer to zero than 'value', the closest first.
+pub fn shrink_i64(value: &i64) -> Vec<i64> {
+    let value = *value;
+    let mut candidates: Vec<i64> = vec![0, value / 2, value - value.signum()];
+    candidates.dedup();
+    candidates.retain(|c| *c != value);
+    candidates
+}
+
+/// Returns candidates that are closer to zero than 'value', the closest first.
+pub fn shrink_usize(value: &usize) -> Vec<usize> {
+    shrink_i64(&(*value as i64)).into_iter().map(|c| c as usize).collect()
+}
+
+/// Returns candidates that are shorter than 'vec', the shortest first, followed by
+/// candidates where one element has been shrunk with 'shrink_element'.
+pub fn shrink_vec<T: Clone>(vec: &[T], shrink_element: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates: Vec<Vec<T>> = Vec::new();
+    if !vec.is_empty() {
+        candidates.push(Vec::new());
+    }
+    if vec.len() > 1 {
+        candidates.push(vec[..vec.len() / 2].to_vec());
+        candidates.push(vec[vec.len() / 2..].to_vec());
+    }
+    for i in 0..vec.len() {
+        let mut removed = vec.to_vec();
+        removed.remove(i);
+        candidates.push(removed);
+    }
+    for (i, element) in vec.iter().enumerate() {
+        for smaller in shrink_element(element) {
+            let mut replaced = vec.to_vec();
+            replaced[i] = smaller;
+            candidates.push(replaced);
+        }
+    }
+    candidates
+}
+
+/// Returns candidates that are shorter than 's', or where one char has been replaced by 'a'.
+/// Takes '&String' to fit the 'shrink' parameter of 'check' for String values.
+#[allow(clippy::ptr_arg)]
+pub fn shrink_string(s: &String) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    shrink_vec(&chars, |c| if *c == 'a' { vec![] } else { vec!['a'] })
+        .into_iter()
+        .map(|chars| chars.into_iter().collect())
+        .collect()
+}
+
+/// Returns a random string of up to 'size' chars from 'alphabet'.
+pub fn random_string(random: &mut Random, size: usize, alphabet: &str) -> String {
+    let chars: Vec<char> = alphabet.chars().collect();
+    let len = random.range(0, size as i64) as usize;
+    (0..len).map(|_| *random.choose(&chars)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: Config = Config { cases: 100, seed: 1, max_size: 20 };
+
+    fn random_vec(random: &mut Random, size: usize) -> Vec<i64> {
+        let len = random.range(0, size as i64);
+        (0..len).map(|_| random.range(-100, 100)).collect()
+    }
+
+    #[test]
+    fn test_true_property_has_no_counterexample() {
+        let result = find_counterexample(CONFIG, random_vec, |v| shrink_vec(v, shrink_i64), |v| v.len() <= 20);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_can_shrink_counterexample() {
+        let result = find_counterexample(CONFIG, random_vec, |v| shrink_vec(v, shrink_i64), |v| v.iter().sum::<i64>() < 50)
+            .expect("property is false");
+        assert!(result.original.iter().sum::<i64>() >= 50);
+        assert_eq!(result.value, vec![50]);
+    }
+
+    #[test]
+    fn test_panic_is_counterexample() {
+        let result = find_counterexample(CONFIG, |r, _| r.range(0, 10), shrink_i64, |v| 10 / v > 0)
+            .expect("property panics");
+        assert_eq!(result.value, 0);
+    }
+
+    #[test]
+    fn test_can_shrink_i64() {
+        assert_eq!(shrink_i64(&0), Vec::<i64>::new());
+        assert_eq!(shrink_i64(&1), vec![0]);
+        assert_eq!(shrink_i64(&-9), vec![0, -4, -8]);
+    }
+
+    #[test]
+    fn test_can_shrink_string() {
+        let candidates = shrink_string(&String::from("ab"));
+        assert_eq!(candidates, vec!["", "a", "b", "b", "a", "aa"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "shrunk in")]
+    fn test_check_panics_with_counterexample() {
+        check(|r, size| random_string(r, size, "ab"), shrink_string, |s| !s.contains("bb"));
+    }
+}
@@ -0,0 +1,86 @@
+/// A small, seedable pseudo random number generator (SplitMix64). The same seed
+/// always produces the same sequence of numbers, on all platforms.
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    /// Creates a new generator from 'seed'.
+    pub const fn new(seed: u64) -> Self {
+        Random { state: seed }
+    }
+
+    /// Returns the next random u64.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number in the inclusive range 'from'..='to'.
+    pub fn range(&mut self, from: i64, to: i64) -> i64 {
+        assert!(from <= to, "empty range: {}..={}", from, to);
+        let span = (to as i128 - from as i128 + 1) as u128;
+        (from as i128 + (self.next_u64() as u128 % span) as i128) as i64
+    }
+
+    /// Returns a random index in the range 0..'len'.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0, len as i64 - 1) as usize
+    }
+
+    /// Returns true with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// Returns a random element of 'items'.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Shuffles 'items' in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_gives_same_numbers() {
+        let a: Vec<u64> = (0..10).scan(Random::new(17), |r, _| Some(r.next_u64())).collect();
+        let b: Vec<u64> = (0..10).scan(Random::new(17), |r, _| Some(r.next_u64())).collect();
+        let c: Vec<u64> = (0..10).scan(Random::new(18), |r, _| Some(r.next_u64())).collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_range_is_inclusive() {
+        let mut random = Random::new(1);
+        let values: Vec<i64> = (0..1000).map(|_| random.range(-2, 2)).collect();
+        assert_eq!(*values.iter().min().unwrap(), -2);
+        assert_eq!(*values.iter().max().unwrap(), 2);
+        assert_eq!(random.range(i64::MIN, i64::MIN), i64::MIN);
+        random.range(i64::MIN, i64::MAX);
+    }
+
+    #[test]
+    fn test_can_shuffle() {
+        let mut random = Random::new(5);
+        let mut items: Vec<i32> = (0..20).collect();
+        random.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<i32>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<i32>>());
+    }
+}
@@ -0,0 +1,235 @@
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+use crate::answers::{Answers, EXAMPLE};
+use crate::days::{get_solution, DAYS};
+use crate::input::{get_inputs, puzzle};
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Status {
+    /// The answer is equal to the known answer.
+    Pass,
+    /// The answer differs from the known answer.
+    Fail,
+    /// The input or the known answer is missing.
+    Missing,
+    /// The solution panicked.
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Missing => "missing",
+            Status::Error => "error",
+        };
+        f.pad(s)
+    }
+}
+
+/// The result of verifying one part of one day for one user.
+#[derive(Debug, Clone)]
+pub struct Verification {
+    pub user: String,
+    pub day: u32,
+    pub part: u32,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub status: Status,
+    /// The time spent parsing the input. Parsing is done once per day, so only
+    /// part 1 records it.
+    pub parse_time: Duration,
+    /// The time spent solving the part, without parsing.
+    pub time: Duration,
+}
+
+/// Solves both parts of 'day' with the given input, or returns None if the day has no solution.
+pub fn solve(day: u32, input: &str) -> Option<(String, String)> {
+    let mut solution = get_solution(day)?;
+    solution.parse(input);
+    Some((solution.part_1(), solution.part_2()))
+}
+
+/// Solves both parts of 'day' with its puzzle input, and prints the answers.
+/// This is all the per-day binaries do.
+pub fn print_answers(day: u32) {
+    let (part_1, part_2) = solve(day, &puzzle(day)).unwrap_or_else(|| panic!("No solution for day {}", day));
+    println!("Part 1: {}", part_1);
+    println!("Part 2: {}", part_2);
+}
+
+/// Verifies all implemented days against the inputs and answers of 'user'.
+/// The pseudo user "example" verifies the worked examples.
+pub fn verify_user(user: &str) -> Vec<Verification> {
+    let answers = Answers::read(user);
+    DAYS.iter()
+        .flat_map(|day| {
+            let inputs = get_inputs(*day);
+            let input = if user == EXAMPLE {
+                Some(inputs.example.read())
+            } else {
+                inputs.puzzle.read_for_user(user)
+            };
+            verify_day(user, *day, input.as_deref(), &answers)
+        })
+        .collect()
+}
+
+/// Verifies both parts of 'day' with the given input against the known answers.
+pub fn verify_day(user: &str, day: u32, input: Option<&str>, answers: &Answers) -> Vec<Verification> {
+    let result = |part: u32, actual: Option<String>, status: Status, parse_time: Duration, time: Duration| Verification {
+        user: String::from(user),
+        day,
+        part,
+        expected: answers.get(day, part).map(String::from),
+        actual,
+        status,
+        parse_time,
+        time,
+    };
+
+    let (mut solution, input) = match (get_solution(day), input) {
+        (Some(solution), Some(input)) => (solution, input),
+        _ => return vec![
+            result(1, None, Status::Missing, Duration::ZERO, Duration::ZERO),
+            result(2, None, Status::Missing, Duration::ZERO, Duration::ZERO),
+        ],
+    };
+
+    let start = Instant::now();
+    if catch_unwind(AssertUnwindSafe(|| solution.parse(input))).is_err() {
+        let parse_time = start.elapsed();
+        return vec![
+            result(1, None, Status::Error, parse_time, Duration::ZERO),
+            result(2, None, Status::Error, Duration::ZERO, Duration::ZERO),
+        ];
+    }
+    let parse_time = start.elapsed();
+
+    [1, 2].iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = catch_unwind(AssertUnwindSafe(|| {
+                if *part == 1 { solution.part_1() } else { solution.part_2() }
+            }));
+            let time = start.elapsed();
+            let parse_time = if *part == 1 { parse_time } else { Duration::ZERO };
+            match (answer, answers.get(day, *part)) {
+                (Err(_), _) => result(*part, None, Status::Error, parse_time, time),
+                (Ok(actual), None) => result(*part, Some(actual), Status::Missing, parse_time, time),
+                (Ok(actual), Some(expected)) => {
+                    let status = if actual == expected { Status::Pass } else { Status::Fail };
+                    result(*part, Some(actual), status, parse_time, time)
+                }
+            }
+        })
+        .collect()
+}
+
+/// Formats the verification results as a table, followed by a summary line.
+pub fn format_table(results: &[Verification]) -> String {
+    let width = |f: fn(&Verification) -> usize, title: &str| {
+        results.iter().map(f).chain([title.len()]).max().unwrap_or(0)
+    };
+    let user_width = width(|v| v.user.len(), "User");
+    let expected_width = width(|v| v.expected.as_deref().unwrap_or("-").len(), "Expected");
+    let actual_width = width(|v| v.actual.as_deref().unwrap_or("-").len(), "Actual");
+
+    let mut table = format!(
+        "{:<uw$}  Day  Part  {:<ew$}  {:<aw$}  Status   {:>12}  {:>12}\n",
+        "User", "Expected", "Actual", "Parse", "Time",
+        uw = user_width, ew = expected_width, aw = actual_width,
+    );
+    for v in results {
+        table += &format!(
+            "{:<uw$}  {:>3}  {:>4}  {:<ew$}  {:<aw$}  {:<7}  {:>9.3} ms  {:>9.3} ms\n",
+            v.user, v.day, v.part,
+            v.expected.as_deref().unwrap_or("-"),
+            v.actual.as_deref().unwrap_or("-"),
+            v.status,
+            v.parse_time.as_secs_f64() * 1000.0,
+            v.time.as_secs_f64() * 1000.0,
+            uw = user_width, ew = expected_width, aw = actual_width,
+        );
+    }
+
+    let count = |status: Status| results.iter().filter(|v| v.status == status).count();
+    let total: Duration = results.iter().map(|v| v.parse_time + v.time).sum();
+    table += &format!(
+        "{} passed, {} failed, {} missing, {} errors in {:.3} ms\n",
+        count(Status::Pass), count(Status::Fail), count(Status::Missing), count(Status::Error),
+        total.as_secs_f64() * 1000.0,
+    );
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::DEFAULT_USER;
+    use super::*;
+
+    #[test]
+    fn test_can_solve() {
+        let answers = (String::from("7"), String::from("19"));
+        assert_eq!(solve(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb"), Some(answers));
+        assert_eq!(solve(26, ""), None);
+    }
+
+    #[test]
+    fn test_can_verify_examples() {
+        let results = verify_user(EXAMPLE);
+        assert_eq!(results.len(), 2 * DAYS.len());
+        // Days without known answers are skipped
+        for v in results.iter().filter(|v| v.expected.is_some()) {
+            assert_eq!(v.status, Status::Pass, "day {} part {}", v.day, v.part);
+        }
+    }
+
+    #[test]
+    fn test_can_verify_puzzle_inputs() {
+        let results = verify_user(DEFAULT_USER);
+        assert_eq!(results.len(), 2 * DAYS.len());
+        // Days without known answers are skipped
+        for v in results.iter().filter(|v| v.expected.is_some()) {
+            assert_eq!(v.status, Status::Pass, "day {} part {}", v.day, v.part);
+        }
+    }
+
+    #[test]
+    fn test_can_verify_missing_input() {
+        let results = verify_user("no-such-user");
+        assert!(results.iter().all(|v| v.status == Status::Missing));
+    }
+
+    #[test]
+    fn test_can_verify_day() {
+        let answers = Answers::parse("6 1 7\n6 2 20\n");
+        let results = verify_day("test", 6, Some("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), &answers);
+        assert_eq!(results[0].status, Status::Pass);
+        assert_eq!(results[1].status, Status::Fail);
+        assert_eq!(results[1].actual.as_deref(), Some("19"));
+        assert_eq!(results[1].parse_time, Duration::ZERO);
+
+        let results = verify_day("test", 6, Some("abc"), &Answers::default());
+        assert_eq!(results[0].status, Status::Error);
+        assert_eq!(results[1].status, Status::Error);
+
+        let results = verify_day("test", 25, Some("abc"), &answers);
+        assert_eq!(results[0].status, Status::Missing);
+    }
+
+    #[test]
+    fn test_can_format_table() {
+        let answers = Answers::parse("6 1 7\n");
+        let results = verify_day("test", 6, Some("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), &answers);
+        let table = format_table(&results);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("User  Day  Part  Expected  Actual  Status"));
+        assert!(lines[1].starts_with("test    6     1  7         7       pass"));
+        assert!(lines[2].starts_with("test    6     2  -         19      missing"));
+        assert!(lines[3].starts_with("1 passed, 0 failed, 1 missing, 0 errors in"));
+    }
+}
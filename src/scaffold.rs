@@ -0,0 +1,331 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The root of the crate, where new days are created by default.
+pub const CRATE_ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+/// The answer of a solution that is yet to be written. New days have no known answers,
+/// so verification reports them as missing until they are registered.
+pub const PLACEHOLDER_ANSWER: &str = "?";
+
+/// Returns the source code of a new day module, with a solution that is yet to be
+/// written, and a test that runs the solution on the worked example.
+pub fn create_module_source(day: u32) -> String {
+    format!(r#"use crate::lines;
+use crate::input::puzzle;
+use crate::solution::Solution;
+
+pub fn read_input() -> Vec<String> {{
+    lines(puzzle({day}).as_bytes()).collect()
+}}
+
+#[derive(Default)]
+pub struct Day{day:02} {{
+    #[allow(dead_code)] // Read by the solution, once it is written
+    lines: Vec<String>,
+}}
+
+impl Solution for Day{day:02} {{
+    fn parse(&mut self, input: &str) {{
+        self.lines = lines(input.as_bytes()).collect();
+    }}
+
+    fn part_1(&self) -> String {{
+        // Replace with the solution of part 1
+        String::from("{placeholder}")
+    }}
+
+    fn part_2(&self) -> String {{
+        // Replace with the solution of part 2
+        String::from("{placeholder}")
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use crate::input::example;
+    use super::*;
+
+    #[test]
+    fn test_with_example() {{
+        let mut solution = Day{day:02}::default();
+        solution.parse(&example({day}));
+        // Replace with the answers from the puzzle text
+        assert_eq!(solution.part_1(), "{placeholder}");
+        assert_eq!(solution.part_2(), "{placeholder}");
+    }}
+}}
+"#, day = day, placeholder = PLACEHOLDER_ANSWER)
+}
+
+/// Returns the source code of the binary of 'day', which prints its answers.
+pub fn create_binary_source(day: u32) -> String {
+    format!("fn main() {{\n    aoc_2022::runner::print_answers({});\n}}\n", day)
+}
+
+/// Registers the module and solution of 'day' in the source of the day registry,
+/// keeping the modules, the days and the solutions ordered by day.
+pub fn register_solution(source: &str, day: u32) -> Result<String, String> {
+    let module_line = format!("pub mod day{:02};", day);
+    if source.lines().any(|line| line.trim() == module_line) {
+        return Err(format!("Day {} is already registered", day));
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    for line in source.lines() {
+        let days = line.trim().strip_prefix("pub const DAYS: &[u32] = &[").and_then(|s| s.strip_suffix("];"));
+        match days {
+            Some(days) => {
+                let mut days: Vec<u32> = days.split(',')
+                    .filter_map(|d| d.trim().parse().ok())
+                    .collect();
+                days.push(day);
+                days.sort();
+                let days: Vec<String> = days.iter().map(u32::to_string).collect();
+                lines.push(format!("pub const DAYS: &[u32] = &[{}];", days.join(", ")));
+            }
+            None => lines.push(String::from(line)),
+        }
+    }
+
+    let module_day = |line: &str| line.strip_prefix("pub mod day")?.strip_suffix(';')?.parse().ok();
+    insert_ordered(&mut lines, day, module_day, module_line)?;
+    let solution_day = |line: &str| line.split_once(" => Some(Box::<day")?.0.parse().ok();
+    let solution_line = format!("{} => Some(Box::<day{:02}::Day{:02}>::default()),", day, day, day);
+    insert_ordered(&mut lines, day, solution_day, solution_line)?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Registers the puzzle input and the worked example of 'day' in the source of the input
+/// registry, keeping the inputs ordered by day.
+pub fn register_inputs(source: &str, day: u32) -> Result<String, String> {
+    let inputs_line = format!("day_inputs!({}, \"day{:02}.txt\", \"example{:02}.txt\"),", day, day, day);
+    if source.lines().any(|line| line.trim() == inputs_line) {
+        return Err(format!("Inputs for day {} are already registered", day));
+    }
+
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    let inputs_day = |line: &str| line.strip_prefix("day_inputs!(")?.split_once(',')?.0.parse().ok();
+    insert_ordered(&mut lines, day, inputs_day, inputs_line)?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts 'new_line' among the consecutive lines for which 'day_of' returns a day, before
+/// the first one of a later day, or after the last one, with the same indentation.
+fn insert_ordered(lines: &mut Vec<String>, day: u32, day_of: impl Fn(&str) -> Option<u32>, new_line: String) -> Result<(), String> {
+    let days: Vec<(usize, u32)> = lines.iter()
+        .enumerate()
+        .filter_map(|(index, line)| day_of(line.trim()).map(|d| (index, d)))
+        .collect();
+    let &(last, _) = days.last().ok_or_else(|| format!("Found no place to insert: {}", new_line))?;
+    let index = days.iter()
+        .find(|(_, d)| *d > day)
+        .map_or(last + 1, |(index, _)| *index);
+    let neighbour = &lines[index.min(last)];
+    let indent = &neighbour[..neighbour.len() - neighbour.trim_start().len()];
+    let new_line = format!("{}{}", indent, new_line);
+    lines.insert(index, new_line);
+    Ok(())
+}
+
+/// Writes all 'files' next to their destinations first, and only then renames them over
+/// the destinations, so a failed write changes nothing and no file is ever half written.
+fn write_all(files: &[(&PathBuf, String)]) -> Result<(), String> {
+    let temporary = |path: &Path| path.with_file_name(format!(".{}.new", path.file_name().unwrap_or_default().to_string_lossy()));
+    let mut written: Vec<PathBuf> = Vec::new();
+    let result = files.iter().try_for_each(|(path, contents)| {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let temp = temporary(path);
+        fs::write(&temp, contents).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        written.push(temp);
+        Ok(())
+    });
+    if result.is_err() {
+        for temp in &written {
+            let _ = fs::remove_file(temp);
+        }
+        return result;
+    }
+    files.iter().try_for_each(|(path, _)| {
+        fs::rename(temporary(path), path).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    })
+}
+
+/// Creates a new day in the crate at 'root': the day module and binary, placeholders for
+/// the puzzle input and the worked example, and the registration of the solution and the
+/// inputs. No answers are registered, so the new day is reported as missing by 'verify'. Refuses to overwrite anything that already exists. Returns
+/// the created and changed files.
+pub fn create_day(root: &Path, day: u32) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day must be between 1 and 25: {}", day));
+    }
+
+    let module = root.join(format!("src/days/day{:02}.rs", day));
+    let puzzle = root.join(format!("input/day{:02}.txt", day));
+    let example = root.join(format!("input/example{:02}.txt", day));
+    let binary = root.join(format!("src/bin/day{:02}.rs", day));
+    for path in [&module, &puzzle, &example, &binary] {
+        if path.exists() {
+            return Err(format!("File already exists: {}", path.display()));
+        }
+    }
+
+    let registry = root.join("src/days/mod.rs");
+    let inputs = root.join("src/input.rs");
+    let read = |path: &Path| fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e));
+    let new_registry = register_solution(&read(&registry)?, day)?;
+    let new_inputs = register_inputs(&read(&inputs)?, day)?;
+
+    let files = [
+        (&module, create_module_source(day)),
+        (&binary, create_binary_source(day)),
+        (&puzzle, String::new()),
+        (&example, String::new()),
+        (&registry, new_registry),
+        (&inputs, new_inputs),
+    ];
+    write_all(&files)?;
+    Ok(files.iter().map(|(path, _)| path.to_path_buf()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use super::*;
+
+    // The registries as they look before a new day is created. The real ones change
+    // with every new day, so the tests do not read them.
+    const REGISTRY: &str = "\
+use crate::solution::Solution;
+
+pub mod day01;
+pub mod day09;
+
+/// The days that have a solution, in order.
+pub const DAYS: &[u32] = &[1, 9];
+
+pub fn get_solution(day: u32) -> Option<Box<dyn Solution>> {
+    match day {
+        1 => Some(Box::<day01::Day01>::default()),
+        9 => Some(Box::<day09::Day09>::default()),
+        _ => None,
+    }
+}
+";
+    const INPUTS: &str = "\
+/// All registered inputs, ordered by day.
+pub const INPUTS: &[DayInputs] = &[
+    day_inputs!(1, \"day01.txt\", \"example01.txt\"),
+    day_inputs!(9, \"day09.txt\", \"example09.txt\"),
+];
+";
+
+    #[test]
+    fn test_can_create_module_source() {
+        let source = create_module_source(10);
+        assert!(source.contains("pub struct Day10 {"));
+        assert!(source.contains("impl Solution for Day10 {"));
+        assert!(source.contains("lines(puzzle(10).as_bytes())"));
+        assert!(source.contains("solution.parse(&example(10));"));
+        assert!(!source.contains("todo!"));
+        assert_eq!(create_binary_source(10), "fn main() {\n    aoc_2022::runner::print_answers(10);\n}\n");
+    }
+
+    #[test]
+    fn test_can_register_solution() {
+        let source = register_solution(REGISTRY, 10).unwrap();
+        assert!(source.contains("pub mod day09;\npub mod day10;\n"));
+        assert!(source.contains("pub const DAYS: &[u32] = &[1, 9, 10];"));
+        assert!(source.contains(
+            "        9 => Some(Box::<day09::Day09>::default()),\n        10 => Some(Box::<day10::Day10>::default()),\n        _ => None,"));
+        assert_eq!(register_solution(&source, 10), Err(String::from("Day 10 is already registered")));
+    }
+
+    #[test]
+    fn test_can_register_solution_between_days() {
+        let source = register_solution(REGISTRY, 5).unwrap();
+        assert!(source.contains("pub mod day01;\npub mod day05;\npub mod day09;\n"));
+        assert!(source.contains("pub const DAYS: &[u32] = &[1, 5, 9];"));
+        assert!(source.contains(concat!(
+            "        1 => Some(Box::<day01::Day01>::default()),\n",
+            "        5 => Some(Box::<day05::Day05>::default()),\n",
+            "        9 => Some(Box::<day09::Day09>::default()),\n")));
+    }
+
+    #[test]
+    fn test_can_register_inputs() {
+        let source = register_inputs(INPUTS, 10).unwrap();
+        assert!(source.contains(concat!(
+            "    day_inputs!(9, \"day09.txt\", \"example09.txt\"),\n",
+            "    day_inputs!(10, \"day10.txt\", \"example10.txt\"),\n",
+            "];")));
+        assert!(register_inputs(&source, 10).is_err());
+    }
+
+    #[test]
+    fn test_can_register_inputs_between_days() {
+        let source = register_inputs(INPUTS, 5).unwrap();
+        assert!(source.contains(concat!(
+            "    day_inputs!(1, \"day01.txt\", \"example01.txt\"),\n",
+            "    day_inputs!(5, \"day05.txt\", \"example05.txt\"),\n",
+            "    day_inputs!(9, \"day09.txt\", \"example09.txt\"),\n")));
+    }
+
+    #[test]
+    fn test_can_create_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("input")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+        fs::write(root.join("src/input.rs"), INPUTS).unwrap();
+
+        let files = create_day(&root, 11).unwrap();
+        assert_eq!(files.len(), 6);
+        assert!(root.join("src/days/day11.rs").exists());
+        assert!(root.join("src/bin/day11.rs").exists());
+        assert!(root.join("input/day11.txt").exists());
+        assert!(root.join("input/example11.txt").exists());
+        assert!(fs::read_to_string(root.join("src/days/mod.rs")).unwrap().contains("pub mod day11;"));
+        assert!(!root.join("answers").exists());
+
+        // Existing days are never overwritten
+        let result = create_day(&root, 11);
+        assert!(result.unwrap_err().starts_with("File already exists"));
+        fs::remove_file(root.join("src/days/day11.rs")).unwrap();
+        fs::remove_file(root.join("src/bin/day11.rs")).unwrap();
+        fs::remove_file(root.join("input/day11.txt")).unwrap();
+        fs::remove_file(root.join("input/example11.txt")).unwrap();
+        assert_eq!(create_day(&root, 11), Err(String::from("Day 11 is already registered")));
+
+        assert!(create_day(&root, 26).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_can_create_days_out_of_order() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-order-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+        fs::write(root.join("src/input.rs"), INPUTS).unwrap();
+
+        create_day(&root, 12).unwrap();
+        create_day(&root, 10).unwrap();
+        let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        assert!(registry.contains("pub mod day09;\npub mod day10;\npub mod day12;\n"));
+        assert!(registry.contains("        10 => Some(Box::<day10::Day10>::default()),\n        12 => Some(Box::<day12::Day12>::default()),\n"));
+        let inputs = fs::read_to_string(root.join("src/input.rs")).unwrap();
+        assert!(inputs.contains("day_inputs!(10, \"day10.txt\", \"example10.txt\"),\n    day_inputs!(12,"));
+
+        // A failed write leaves no files behind, not even temporary ones
+        let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        fs::remove_dir_all(root.join("src/bin")).unwrap();
+        fs::write(root.join("src/bin"), "not a directory").unwrap();
+        assert!(create_day(&root, 11).unwrap_err().starts_with("Failed to create"));
+        assert!(!root.join("src/days/day11.rs").exists());
+        assert_eq!(fs::read_dir(root.join("src/days")).unwrap().count(), 3);
+        assert_eq!(fs::read_to_string(root.join("src/days/mod.rs")).unwrap(), registry);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
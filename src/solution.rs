@@ -0,0 +1,12 @@
+/// A solution to the puzzle of a single day. The puzzle input is parsed once,
+/// and the parsed input is then used to solve both parts of the puzzle.
+pub trait Solution {
+    /// Parses the puzzle input, and stores the result for solving the parts.
+    fn parse(&mut self, input: &str);
+
+    /// Solves part 1 of the puzzle, using the parsed input.
+    fn part_1(&self) -> String;
+
+    /// Solves part 2 of the puzzle, using the parsed input.
+    fn part_2(&self) -> String;
+}